@@ -3,7 +3,15 @@ use std::fs::create_dir_all;
 
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
-use gas_fees_benchmark::msg::{BenchmarkExecuteMsg, BenchmarkQueryMsg, InstantiateMsg};
+use gas_fees_benchmark::msg::{
+    AllNftInfoResponse, ApprovedForAllResponse, BenchmarkExecuteMsg, BenchmarkQueryMsg,
+    ConfigResponse, ContractInfoResponse, DisputesResponse, DocumentsResponse, InstantiateMsg,
+    LeaseResponse, LeasesResponse, LiensResponse, ListingResponse, ListingsResponse,
+    NftInfoResponse, NumTokensResponse, OffersResponse, OwnerOfResponse, OwnerTaxResponse,
+    PendingTransferResponse, PropertiesResponse, PropertyResponse, ReceiveMsg,
+    RegistrationRequestResponse, RegistrationRequestsResponse, SaleResponse, TaxPolicyResponse,
+    TaxResponse, TaxSaleResponse, TitleHistoryResponse, TokensResponse, VerifyDocumentResponse,
+};
 
 fn main() {
     let mut out_dir = current_dir().unwrap();
@@ -14,6 +22,35 @@ fn main() {
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(BenchmarkExecuteMsg), &out_dir);
     export_schema(&schema_for!(BenchmarkQueryMsg), &out_dir);
+    export_schema(&schema_for!(ReceiveMsg), &out_dir);
+    export_schema(&schema_for!(ConfigResponse), &out_dir);
+    export_schema(&schema_for!(PropertyResponse), &out_dir);
+    export_schema(&schema_for!(PropertiesResponse), &out_dir);
+    export_schema(&schema_for!(TitleHistoryResponse), &out_dir);
+    export_schema(&schema_for!(PendingTransferResponse), &out_dir);
+    export_schema(&schema_for!(SaleResponse), &out_dir);
+    export_schema(&schema_for!(LiensResponse), &out_dir);
+    export_schema(&schema_for!(DisputesResponse), &out_dir);
+    export_schema(&schema_for!(RegistrationRequestResponse), &out_dir);
+    export_schema(&schema_for!(RegistrationRequestsResponse), &out_dir);
+    export_schema(&schema_for!(DocumentsResponse), &out_dir);
+    export_schema(&schema_for!(VerifyDocumentResponse), &out_dir);
+    export_schema(&schema_for!(TaxPolicyResponse), &out_dir);
+    export_schema(&schema_for!(TaxResponse), &out_dir);
+    export_schema(&schema_for!(OwnerTaxResponse), &out_dir);
+    export_schema(&schema_for!(TaxSaleResponse), &out_dir);
+    export_schema(&schema_for!(LeaseResponse), &out_dir);
+    export_schema(&schema_for!(LeasesResponse), &out_dir);
+    export_schema(&schema_for!(ListingResponse), &out_dir);
+    export_schema(&schema_for!(ListingsResponse), &out_dir);
+    export_schema(&schema_for!(OffersResponse), &out_dir);
+    export_schema(&schema_for!(OwnerOfResponse), &out_dir);
+    export_schema(&schema_for!(ApprovedForAllResponse), &out_dir);
+    export_schema(&schema_for!(NumTokensResponse), &out_dir);
+    export_schema(&schema_for!(ContractInfoResponse), &out_dir);
+    export_schema(&schema_for!(NftInfoResponse), &out_dir);
+    export_schema(&schema_for!(AllNftInfoResponse), &out_dir);
+    export_schema(&schema_for!(TokensResponse), &out_dir);
     // export_schema(&schema_for!(State), &out_dir);
     // export_schema(&schema_for!(CountResponse), &out_dir);
 }
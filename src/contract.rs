@@ -4,15 +4,35 @@
 use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Coin, Deps, DepsMut, DistributionMsg, Env, MessageInfo, Response,
-    StakingMsg, StdResult, Uint128, StdError,
+    from_binary, to_binary, Addr, Api, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut,
+    DistributionMsg, Env, MessageInfo, Order, Response, StakingMsg, StdError, StdResult, Storage,
+    Timestamp, Uint128, WasmMsg,
 };
+use cw0::Expiration;
 use cw2::set_contract_version;
-use cw_storage_plus::U64Key;
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg, Denom};
+use cw_storage_plus::{Bound, PrimaryKey, U64Key};
 
 use crate::error::ContractError;
-use crate::msg::{BenchmarkExecuteMsg, BenchmarkQueryMsg, InstantiateMsg};
-use crate::state::{State, STATE, owner_of_registry_index};
+use crate::msg::{
+    AllNftInfoResponse, Approval, ApprovedForAllResponse, BenchmarkExecuteMsg, BenchmarkQueryMsg,
+    ConfigResponse, ContractInfoResponse, Cw721ReceiveMsg, DisputesResponse, DocumentsResponse,
+    InstantiateMsg, LeaseResponse, LeasesResponse, LiensResponse, ListingResponse,
+    ListingsResponse, NftInfoResponse, NumTokensResponse, OffersResponse, OwnerOfResponse,
+    OwnerTaxResponse, ParcelInfo, PendingTransferResponse, PropertiesResponse, PropertyResponse,
+    ReceiveMsg, RegistrationRequestResponse, RegistrationRequestsResponse, SaleResponse,
+    TaxPolicyResponse, TaxResponse, TaxSaleResponse, TitleHistoryResponse, TokensResponse,
+    VerifyDocumentResponse,
+};
+use crate::state::{
+    leases, properties, Asset, Beneficiary, ClaimStatus, Config, DepositClaim, Dispute,
+    DisputeOutcome, Document, DocumentType, Encumbrance, EncumbranceKind, LandUse, Lease,
+    LeaseStatus, Lien, Listing, NftApproval, Offer, OfferStatus, PendingTransfer, Property,
+    PropertyStatus, RegistrationRequest, RegistrationStatus, Sale, State, TaxAccount, TaxPolicy,
+    TaxRate, TaxSale, TaxSaleBid, TitleTransfer, CONFIG, DISPUTES, DOCUMENTS, ENCUMBRANCES, LIENS,
+    LISTINGS, MINIMUM_OFFERS, NFT_APPROVALS, NFT_OPERATORS, OFFERS, PENDING_TRANSFERS,
+    REGISTRATION_REQUESTS, SALES, STATE, TAX_ACCOUNTS, TAX_POLICY, TAX_SALES, TITLE_HISTORY,
+};
 
 // use terra_cosmwasm::TerraQuerier;
 
@@ -20,21 +40,79 @@ use crate::state::{State, STATE, owner_of_registry_index};
 const CONTRACT_NAME: &str = "crates.io:gas-fees-benchmark";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+// CW721 collection metadata
+const NFT_NAME: &str = "Real Estate Titles";
+const NFT_SYMBOL: &str = "TITLE";
+
+// a registration request stays open for a week unless configured otherwise
+const DEFAULT_REGISTRATION_TTL: u64 = 7 * 24 * 60 * 60;
+const DEFAULT_DEPOSIT_DISPUTE_WINDOW: u64 = 14 * 24 * 60 * 60;
+
+// every open offer is refunded when the title changes hands, so their number
+// is kept small enough to refund in one transaction
+const MAX_OPEN_OFFERS: u32 = 20;
+
+// coordinate bounds in micro-degrees
+const MAX_LATITUDE: i64 = 90_000_000;
+const MAX_LONGITUDE: i64 = 180_000_000;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
     _env: Env,
-    _info: MessageInfo,
-    _msg: InstantiateMsg,
+    info: MessageInfo,
+    msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    let admin = match msg.admin {
+        Some(admin) => deps.api.addr_validate(&admin)?,
+        None => info.sender,
+    };
+    let mut config = Config {
+        admin,
+        registrars: validate_addresses(deps.api, msg.registrars)?,
+        arbitrators: validate_addresses(deps.api, msg.arbitrators)?,
+        approval_threshold: msg.approval_threshold.unwrap_or(1),
+        registration_ttl: msg.registration_ttl.unwrap_or(DEFAULT_REGISTRATION_TTL),
+        accepted_tokens: validate_addresses(deps.api, msg.accepted_tokens)?,
+        registration_fee: None,
+        deposit_dispute_window: msg
+            .deposit_dispute_window
+            .unwrap_or(DEFAULT_DEPOSIT_DISPUTE_WINDOW),
+    };
+    if !config.has_valid_threshold() {
+        return Err(ContractError::InvalidThreshold {});
+    }
+    // the fee may only be charged in one of the accepted tokens
+    config.registration_fee = msg
+        .registration_fee
+        .map(|fee| validate_asset(deps.api, &config, fee))
+        .transpose()?;
     let state = State {
-        num_of_real_state:0,
-        address_of_real_estate:vec![],
+        num_of_real_state: 0,
+        num_tokens: 0,
     };
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    CONFIG.save(deps.storage, &config)?;
     STATE.save(deps.storage, &state)?;
 
-    Ok(Response::new().add_attribute("method", "instantiate"))
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("admin", config.admin))
+}
+
+fn validate_addresses(api: &dyn Api, addresses: Vec<String>) -> StdResult<Vec<Addr>> {
+    let mut validated: Vec<Addr> = vec![];
+    for address in addresses {
+        let address = api.addr_validate(&address)?;
+        if !validated.contains(&address) {
+            validated.push(address);
+        }
+    }
+    Ok(validated)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -45,13 +123,350 @@ pub fn execute(
     msg: BenchmarkExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        BenchmarkExecuteMsg::ChangeOwnerofRealEstate{
+        BenchmarkExecuteMsg::ChangeOwnerofRealEstate {
             house_address,
-            owner_name,
-        }=>state_change_owner_of_real_estate(deps, _env,house_address,owner_name),
+            new_owner,
+            memo,
+        } => state_change_owner_of_real_estate(deps, _env, info, house_address, new_owner, memo),
         BenchmarkExecuteMsg::PushRealEstateToBlockchain {
+            parcel_id,
             house_address,
-        } => state_push_realestate_to_blockchain(deps, _env, house_address),
+            area,
+            land_use,
+            coordinates,
+            owner,
+        } => state_push_realestate_to_blockchain(
+            deps,
+            _env,
+            info.sender.clone(),
+            native_payment(&info),
+            ParcelInfo {
+                parcel_id,
+                house_address,
+                area,
+                land_use,
+                coordinates,
+            },
+            owner,
+        ),
+        BenchmarkExecuteMsg::ProposeTransfer {
+            house_index,
+            recipient,
+            expires,
+            memo,
+        } => state_propose_transfer(deps, _env, info, house_index, recipient, expires, memo),
+        BenchmarkExecuteMsg::AcceptTransfer { house_index } => {
+            state_accept_transfer(deps, _env, info, house_index)
+        }
+        BenchmarkExecuteMsg::CancelTransfer { house_index } => {
+            state_cancel_transfer(deps, _env, info, house_index)
+        }
+        BenchmarkExecuteMsg::RejectTransfer { house_index } => {
+            state_reject_transfer(deps, _env, info, house_index)
+        }
+        BenchmarkExecuteMsg::OpenSale {
+            house_index,
+            price,
+            denom,
+            buyer,
+        } => state_open_sale(deps, _env, info, house_index, price, denom, buyer),
+        BenchmarkExecuteMsg::DepositSaleFunds { house_index } => state_deposit_sale_funds(
+            deps,
+            _env,
+            info.sender.clone(),
+            native_payment(&info),
+            house_index,
+        ),
+        BenchmarkExecuteMsg::CloseSale { house_index } => {
+            state_close_sale(deps, _env, info, house_index)
+        }
+        BenchmarkExecuteMsg::CancelSale { house_index } => {
+            state_cancel_sale(deps, _env, info, house_index)
+        }
+        BenchmarkExecuteMsg::RegisterLien {
+            house_index,
+            lienholder,
+            amount,
+            denom,
+        } => state_register_lien(deps, _env, info, house_index, lienholder, amount, denom),
+        BenchmarkExecuteMsg::ReleaseLien {
+            house_index,
+            lien_id,
+        } => state_release_lien(deps, _env, info, house_index, lien_id),
+        BenchmarkExecuteMsg::ConsentToTransfer {
+            house_index,
+            lien_id,
+        } => state_consent_to_transfer(deps, _env, info, house_index, lien_id),
+        BenchmarkExecuteMsg::AddEncumbrance {
+            house_index,
+            kind,
+            beneficiary,
+            terms,
+            document_hash,
+        } => state_add_encumbrance(
+            deps,
+            _env,
+            info,
+            house_index,
+            kind,
+            beneficiary,
+            terms,
+            document_hash,
+        ),
+        BenchmarkExecuteMsg::ReleaseEncumbrance {
+            house_index,
+            encumbrance_id,
+        } => state_release_encumbrance(deps, _env, info, house_index, encumbrance_id),
+        BenchmarkExecuteMsg::SubdivideProperty {
+            house_index,
+            children,
+        } => state_subdivide_property(deps, _env, info, house_index, children),
+        BenchmarkExecuteMsg::MergeProperties { indices, merged } => {
+            state_merge_properties(deps, _env, info, indices, merged)
+        }
+        BenchmarkExecuteMsg::ChangePropertyStatus {
+            house_index,
+            status,
+        } => state_change_property_status(deps, _env, info, house_index, status),
+        BenchmarkExecuteMsg::FreezeProperty {
+            house_index,
+            reason,
+            case_ref,
+        } => state_freeze_property(deps, _env, info, house_index, reason, case_ref),
+        BenchmarkExecuteMsg::ResolveDispute {
+            house_index,
+            outcome,
+        } => state_resolve_dispute(deps, _env, info, house_index, outcome),
+        BenchmarkExecuteMsg::ApproveRegistration { house_index } => {
+            state_approve_registration(deps, _env, info, house_index)
+        }
+        BenchmarkExecuteMsg::RejectRegistration { house_index } => {
+            state_reject_registration(deps, _env, info, house_index)
+        }
+        BenchmarkExecuteMsg::ExpireRegistration { house_index } => {
+            state_expire_registration(deps, _env, info, house_index)
+        }
+        BenchmarkExecuteMsg::UpdateRegistrationPolicy {
+            approval_threshold,
+            registration_ttl,
+        } => {
+            state_update_registration_policy(deps, _env, info, approval_threshold, registration_ttl)
+        }
+        BenchmarkExecuteMsg::AnchorDocument {
+            house_index,
+            sha256,
+            uri,
+            document_type,
+            transfer_sequence,
+        } => state_anchor_document(
+            deps,
+            _env,
+            info,
+            house_index,
+            sha256,
+            uri,
+            document_type,
+            transfer_sequence,
+        ),
+        BenchmarkExecuteMsg::UpdateTaxPolicy {
+            authority,
+            denom,
+            rates,
+            grace_period,
+        } => state_update_tax_policy(deps, _env, info, authority, denom, rates, grace_period),
+        BenchmarkExecuteMsg::AssessProperty {
+            house_index,
+            assessed_value,
+        } => state_assess_property(deps, _env, info, house_index, assessed_value),
+        BenchmarkExecuteMsg::PayPropertyTax { house_index } => state_pay_property_tax(
+            deps,
+            _env,
+            info.sender.clone(),
+            native_payment(&info),
+            house_index,
+        ),
+        BenchmarkExecuteMsg::StartTaxSale {
+            house_index,
+            duration,
+        } => state_start_tax_sale(deps, _env, info, house_index, duration),
+        BenchmarkExecuteMsg::BidTaxSale { house_index } => state_bid_tax_sale(
+            deps,
+            _env,
+            info.sender.clone(),
+            native_payment(&info),
+            house_index,
+        ),
+        BenchmarkExecuteMsg::CloseTaxSale { house_index } => {
+            state_close_tax_sale(deps, _env, info, house_index)
+        }
+        BenchmarkExecuteMsg::CreateLease {
+            house_index,
+            tenant,
+            rent,
+            denom,
+            period,
+            start,
+            end,
+            deposit,
+        } => state_create_lease(
+            deps,
+            _env,
+            info,
+            house_index,
+            tenant,
+            Asset {
+                denom,
+                amount: rent,
+            },
+            period,
+            start,
+            end,
+            deposit,
+        ),
+        BenchmarkExecuteMsg::AcceptLease {
+            house_index,
+            lease_id,
+        } => state_accept_lease(
+            deps,
+            _env,
+            info.sender.clone(),
+            native_payment(&info),
+            house_index,
+            lease_id,
+        ),
+        BenchmarkExecuteMsg::ClaimDeposit {
+            house_index,
+            lease_id,
+            amount,
+            reason,
+        } => state_claim_deposit(deps, _env, info, house_index, lease_id, amount, reason),
+        BenchmarkExecuteMsg::DisputeDepositClaim {
+            house_index,
+            lease_id,
+            claim_id,
+        } => state_dispute_deposit_claim(deps, _env, info, house_index, lease_id, claim_id),
+        BenchmarkExecuteMsg::ResolveDepositClaim {
+            house_index,
+            lease_id,
+            claim_id,
+            uphold,
+        } => state_resolve_deposit_claim(deps, _env, info, house_index, lease_id, claim_id, uphold),
+        BenchmarkExecuteMsg::ReleaseDeposit {
+            house_index,
+            lease_id,
+        } => state_release_deposit(deps, _env, info, house_index, lease_id),
+        BenchmarkExecuteMsg::PayRent {
+            house_index,
+            lease_id,
+        } => state_pay_rent(
+            deps,
+            _env,
+            info.sender.clone(),
+            native_payment(&info),
+            house_index,
+            lease_id,
+        ),
+        BenchmarkExecuteMsg::ListForSale {
+            house_index,
+            price,
+            denom,
+            expires,
+        } => state_list_for_sale(
+            deps,
+            _env,
+            info,
+            house_index,
+            Asset {
+                denom,
+                amount: price,
+            },
+            expires,
+        ),
+        BenchmarkExecuteMsg::Delist { house_index } => state_delist(deps, _env, info, house_index),
+        BenchmarkExecuteMsg::BuyListedProperty { house_index } => state_buy_listed_property(
+            deps,
+            _env,
+            info.sender.clone(),
+            native_payment(&info),
+            house_index,
+        ),
+        BenchmarkExecuteMsg::MakeOffer { house_index } => state_make_offer(
+            deps,
+            _env,
+            info.sender.clone(),
+            native_payment(&info),
+            house_index,
+        ),
+        BenchmarkExecuteMsg::CounterOffer {
+            house_index,
+            offer_id,
+            price,
+        } => state_counter_offer(deps, _env, info, house_index, offer_id, price),
+        BenchmarkExecuteMsg::AcceptOffer {
+            house_index,
+            offer_id,
+        } => state_accept_offer(
+            deps,
+            _env,
+            info.sender.clone(),
+            native_payment(&info),
+            house_index,
+            offer_id,
+        ),
+        BenchmarkExecuteMsg::SetMinimumOffer {
+            house_index,
+            minimum,
+        } => state_set_minimum_offer(deps, _env, info, house_index, minimum),
+        BenchmarkExecuteMsg::WithdrawOffer {
+            house_index,
+            offer_id,
+        } => state_withdraw_offer(deps, _env, info, house_index, offer_id),
+        BenchmarkExecuteMsg::TransferNft {
+            recipient,
+            token_id,
+        } => state_transfer_nft(deps, _env, info, recipient, token_id),
+        BenchmarkExecuteMsg::SendNft {
+            contract,
+            token_id,
+            msg,
+        } => state_send_nft(deps, _env, info, contract, token_id, msg),
+        BenchmarkExecuteMsg::Approve {
+            spender,
+            token_id,
+            expires,
+        } => state_approve(deps, _env, info, spender, token_id, expires),
+        BenchmarkExecuteMsg::Revoke { spender, token_id } => {
+            state_revoke(deps, _env, info, spender, token_id)
+        }
+        BenchmarkExecuteMsg::ApproveAll { operator, expires } => {
+            state_approve_all(deps, _env, info, operator, expires)
+        }
+        BenchmarkExecuteMsg::RevokeAll { operator } => state_revoke_all(deps, _env, info, operator),
+        BenchmarkExecuteMsg::AddRegistrar { address } => {
+            state_add_registrar(deps, _env, info, address)
+        }
+        BenchmarkExecuteMsg::RemoveRegistrar { address } => {
+            state_remove_registrar(deps, _env, info, address)
+        }
+        BenchmarkExecuteMsg::AddArbitrator { address } => {
+            state_add_arbitrator(deps, _env, info, address)
+        }
+        BenchmarkExecuteMsg::RemoveArbitrator { address } => {
+            state_remove_arbitrator(deps, _env, info, address)
+        }
+        BenchmarkExecuteMsg::TransferAdmin { new_admin } => {
+            state_transfer_admin(deps, _env, info, new_admin)
+        }
+        BenchmarkExecuteMsg::AddAcceptedToken { address } => {
+            state_add_accepted_token(deps, _env, info, address)
+        }
+        BenchmarkExecuteMsg::RemoveAcceptedToken { address } => {
+            state_remove_accepted_token(deps, _env, info, address)
+        }
+        BenchmarkExecuteMsg::UpdateRegistrationFee { fee } => {
+            state_update_registration_fee(deps, _env, info, fee)
+        }
+        BenchmarkExecuteMsg::Receive(wrapper) => state_receive(deps, _env, info, wrapper),
         BenchmarkExecuteMsg::AddValidator {
             validator_addr,
             vault_denom,
@@ -75,199 +490,6427 @@ pub fn execute(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: BenchmarkQueryMsg) -> StdResult<Binary> {
     match msg {
-        BenchmarkQueryMsg::FindOwnerByHouseIndex {house_index} => to_binary(&query_owner_by_house_index(deps, _env,house_index)?),
-        BenchmarkQueryMsg::FindOwnerByHouseName {house_name} => to_binary(&query_owner_by_house_name(deps, _env,house_name)?),
+        BenchmarkQueryMsg::Config {} => to_binary(&query_config(deps, _env)?),
+        BenchmarkQueryMsg::FindOwnerByHouseIndex { house_index } => {
+            to_binary(&query_owner_by_house_index(deps, _env, house_index)?)
+        }
+        BenchmarkQueryMsg::FindOwnerByHouseName { house_name } => {
+            to_binary(&query_owner_by_house_name(deps, _env, house_name)?)
+        }
+        BenchmarkQueryMsg::FindPropertyByHouseIndex { house_index } => {
+            to_binary(&query_property_by_house_index(deps, _env, house_index)?)
+        }
+        BenchmarkQueryMsg::FindPropertyByHouseName { house_name } => {
+            to_binary(&query_property_by_house_name(deps, _env, house_name)?)
+        }
+        BenchmarkQueryMsg::ListProperties {
+            start_after,
+            limit,
+            status,
+        } => to_binary(&query_list_properties(
+            deps,
+            _env,
+            start_after,
+            limit,
+            status,
+        )?),
+        BenchmarkQueryMsg::PropertiesByOwner {
+            owner,
+            start_after,
+            limit,
+            status,
+        } => to_binary(&query_properties_by_owner(
+            deps,
+            _env,
+            owner,
+            start_after,
+            limit,
+            status,
+        )?),
+        BenchmarkQueryMsg::PendingTransfer { house_index } => {
+            to_binary(&query_pending_transfer(deps, _env, house_index)?)
+        }
+        BenchmarkQueryMsg::Sale { house_index } => to_binary(&query_sale(deps, _env, house_index)?),
+        BenchmarkQueryMsg::ListLiens {
+            house_index,
+            start_after,
+            limit,
+        } => to_binary(&query_list_liens(
+            deps,
+            _env,
+            house_index,
+            start_after,
+            limit,
+        )?),
+        BenchmarkQueryMsg::DisputeHistory {
+            house_index,
+            start_after,
+            limit,
+        } => to_binary(&query_dispute_history(
+            deps,
+            _env,
+            house_index,
+            start_after,
+            limit,
+        )?),
+        BenchmarkQueryMsg::RegistrationRequest { house_index } => {
+            to_binary(&query_registration_request(deps, _env, house_index)?)
+        }
+        BenchmarkQueryMsg::OpenRegistrationRequests { start_after, limit } => to_binary(
+            &query_open_registration_requests(deps, _env, start_after, limit)?,
+        ),
+        BenchmarkQueryMsg::ListDocuments {
+            house_index,
+            start_after,
+            limit,
+        } => to_binary(&query_list_documents(
+            deps,
+            _env,
+            house_index,
+            start_after,
+            limit,
+        )?),
+        BenchmarkQueryMsg::VerifyDocument {
+            house_index,
+            sha256,
+        } => to_binary(&query_verify_document(deps, _env, house_index, sha256)?),
+        BenchmarkQueryMsg::TaxPolicy {} => to_binary(&query_tax_policy(deps, _env)?),
+        BenchmarkQueryMsg::OutstandingTax { house_index } => {
+            to_binary(&query_outstanding_tax(deps, _env, house_index)?)
+        }
+        BenchmarkQueryMsg::OutstandingTaxByOwner {
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&query_outstanding_tax_by_owner(
+            deps,
+            _env,
+            owner,
+            start_after,
+            limit,
+        )?),
+        BenchmarkQueryMsg::TaxSale { house_index } => {
+            to_binary(&query_tax_sale(deps, _env, house_index)?)
+        }
+        BenchmarkQueryMsg::Lease {
+            house_index,
+            lease_id,
+        } => to_binary(&query_lease(deps, _env, house_index, lease_id)?),
+        BenchmarkQueryMsg::ActiveLeases {
+            house_index,
+            start_after,
+            limit,
+        } => to_binary(&query_active_leases(
+            deps,
+            _env,
+            house_index,
+            start_after,
+            limit,
+        )?),
+        BenchmarkQueryMsg::ActiveLeasesByTenant {
+            tenant,
+            start_after,
+            limit,
+        } => to_binary(&query_active_leases_by_tenant(
+            deps,
+            _env,
+            tenant,
+            start_after,
+            limit,
+        )?),
+        BenchmarkQueryMsg::Listing { house_index } => {
+            to_binary(&query_listing(deps, _env, house_index)?)
+        }
+        BenchmarkQueryMsg::ListingsForSale {
+            start_after,
+            limit,
+            max_price,
+            land_use,
+        } => to_binary(&query_listings_for_sale(
+            deps,
+            _env,
+            start_after,
+            limit,
+            max_price,
+            land_use,
+        )?),
+        BenchmarkQueryMsg::OffersByProperty {
+            house_index,
+            start_after,
+            limit,
+        } => to_binary(&query_offers_by_property(
+            deps,
+            _env,
+            house_index,
+            start_after,
+            limit,
+        )?),
+        BenchmarkQueryMsg::OwnerOf {
+            token_id,
+            include_expired,
+        } => to_binary(&query_owner_of(
+            deps,
+            _env,
+            token_id,
+            include_expired.unwrap_or(false),
+        )?),
+        BenchmarkQueryMsg::ApprovedForAll {
+            owner,
+            include_expired,
+            start_after,
+            limit,
+        } => to_binary(&query_approved_for_all(
+            deps,
+            _env,
+            owner,
+            include_expired.unwrap_or(false),
+            start_after,
+            limit,
+        )?),
+        BenchmarkQueryMsg::NumTokens {} => to_binary(&query_num_tokens(deps, _env)?),
+        BenchmarkQueryMsg::ContractInfo {} => to_binary(&query_contract_info(deps, _env)?),
+        BenchmarkQueryMsg::NftInfo { token_id } => {
+            to_binary(&query_nft_info(deps, _env, token_id)?)
+        }
+        BenchmarkQueryMsg::AllNftInfo {
+            token_id,
+            include_expired,
+        } => to_binary(&query_all_nft_info(
+            deps,
+            _env,
+            token_id,
+            include_expired.unwrap_or(false),
+        )?),
+        BenchmarkQueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&query_tokens(deps, _env, owner, start_after, limit)?),
+        BenchmarkQueryMsg::AllTokens { start_after, limit } => {
+            to_binary(&query_all_tokens(deps, _env, start_after, limit)?)
+        }
+        BenchmarkQueryMsg::TitleHistory {
+            house_index,
+            start_after,
+            limit,
+        } => to_binary(&query_title_history(
+            deps,
+            _env,
+            house_index,
+            start_after,
+            limit,
+        )?),
     }
 }
 
-fn state_push_realestate_to_blockchain(
-    deps:DepsMut,
+fn state_add_registrar(
+    deps: DepsMut,
     _env: Env,
-    house_address:String,
-) -> Result<Response,ContractError> {
-    let mut state=STATE.load(deps.storage)?;
-    state.num_of_real_state+=1;
-    state.address_of_real_estate.push((state.num_of_real_state,house_address));
-    STATE.save(deps.storage,&state);
-    Ok(Response::default())
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let registrar = deps.api.addr_validate(&address)?;
+    if config.is_registrar(&registrar) {
+        return Err(ContractError::RegistrarAlreadyExists {});
+    }
+    config.registrars.push(registrar.clone());
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_registrar")
+        .add_attribute("registrar", registrar))
 }
 
-fn state_change_owner_of_real_estate(
-    deps:DepsMut,
-    _env: Env,
-    house_address:String,
-    owner_name:String, 
-) -> Result<Response,ContractError> {
-    let mut house_index:u64 = 0; 
-    let state=STATE.load(deps.storage)?;
-    for house in state.address_of_real_estate{
-        if house_address==house.1{
-            house_index=house.0;
-            break;
-        }
+fn state_remove_registrar(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
     }
-    if house_index==0{
-        return Err(ContractError::RealEstateDoesNotExist{});
+
+    let registrar = deps.api.addr_validate(&address)?;
+    if !config.is_registrar(&registrar) {
+        return Err(ContractError::RegistrarDoesNotExist {});
+    }
+    config.registrars.retain(|r| r != &registrar);
+    if !config.has_valid_threshold() {
+        return Err(ContractError::InvalidThreshold {});
     }
-    owner_of_registry_index.save(deps.storage,(house_index,house_address),&owner_name);
+    CONFIG.save(deps.storage, &config)?;
 
-    Ok(Response::default())
+    Ok(Response::new()
+        .add_attribute("method", "remove_registrar")
+        .add_attribute("registrar", registrar))
 }
 
-fn query_owner_by_house_name(
-    deps:Deps,
+fn state_add_arbitrator(
+    deps: DepsMut,
     _env: Env,
-    house_name:String,
-) -> StdResult<String>{
-    let mut house_index:u64 = 0; 
-    let state=STATE.load(deps.storage)?;
-    for house in state.address_of_real_estate{
-        if house_name==house.1{
-            house_index=house.0;
-            break;
-        }
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
     }
-    if house_index==0{
-        return Err(StdError::GenericErr {
-            msg: "House Not Found in Blockchain".to_string(),
-        });
+
+    let arbitrator = deps.api.addr_validate(&address)?;
+    if config.is_arbitrator(&arbitrator) {
+        return Err(ContractError::ArbitratorAlreadyExists {});
     }
-    let house_result=owner_of_registry_index.load(deps.storage,
-        ( house_index.clone(),house_name.clone() ) )?;
+    config.arbitrators.push(arbitrator.clone());
+    CONFIG.save(deps.storage, &config)?;
 
-    Ok(house_result)
+    Ok(Response::new()
+        .add_attribute("method", "add_arbitrator")
+        .add_attribute("arbitrator", arbitrator))
 }
 
-fn query_owner_by_house_index(
-    deps:Deps,
+fn state_remove_arbitrator(
+    deps: DepsMut,
     _env: Env,
-    house_index:u64,
-) -> StdResult<String>{
-    let mut house_name:String = "Not Found".to_string(); 
-    let mut flag:u64=0;
-    let state=STATE.load(deps.storage)?;
-    for house in state.address_of_real_estate{
-        if house_index==house.0{
-            house_name=house.1;
-            flag=1;
-            break;
-        }
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
     }
-    if flag==1 {
-        return Err(StdError::GenericErr {
-            msg: "House Not Found in Blockchain".to_string(),
-        });
+
+    let arbitrator = deps.api.addr_validate(&address)?;
+    if !config.is_arbitrator(&arbitrator) {
+        return Err(ContractError::ArbitratorDoesNotExist {});
     }
+    config.arbitrators.retain(|a| a != &arbitrator);
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_arbitrator")
+        .add_attribute("arbitrator", arbitrator))
+}
 
-    let house_result=owner_of_registry_index.load(deps.storage,
-        (house_index.clone(),house_name.clone()) )?;
+fn state_transfer_admin(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    new_admin: String,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
 
-    Ok(house_result)
+    config.admin = deps.api.addr_validate(&new_admin)?;
+    CONFIG.save(deps.storage, &config)?;
 
+    Ok(Response::new()
+        .add_attribute("method", "transfer_admin")
+        .add_attribute("admin", config.admin))
 }
 
+fn state_add_accepted_token(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let token = deps.api.addr_validate(&address)?;
+    if config.accepts_token(&token) {
+        return Err(ContractError::TokenAlreadyAccepted {});
+    }
+    config.accepted_tokens.push(token.clone());
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_accepted_token")
+        .add_attribute("token", token))
+}
 
-fn add_validator(
+fn state_remove_accepted_token(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    validator_addr: Addr,
-    vault_denom: String,
+    address: String,
 ) -> Result<Response, ContractError> {
-    // check if the validator exists in the blockchain
-    if deps
-        .querier
-        .query_validator(validator_addr.clone())?
-        .is_none()
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let token = deps.api.addr_validate(&address)?;
+    if !config.accepts_token(&token) {
+        return Err(ContractError::TokenNotAccepted {});
+    }
+    if config
+        .registration_fee
+        .as_ref()
+        .map_or(false, |fee| fee.denom == Denom::Cw20(token.clone()))
     {
-        return Err(ContractError::ValidatorDoesNotExist {});
+        return Err(ContractError::TokenInUse {});
+    }
+    if TAX_POLICY
+        .may_load(deps.storage)?
+        .map_or(false, |policy| policy.denom == Denom::Cw20(token.clone()))
+    {
+        return Err(ContractError::TokenInUse {});
     }
+    config.accepted_tokens.retain(|accepted| accepted != &token);
+    CONFIG.save(deps.storage, &config)?;
 
-    let amount_to_stake_per_validator = Uint128::new(10);
+    Ok(Response::new()
+        .add_attribute("method", "remove_accepted_token")
+        .add_attribute("token", token))
+}
 
-    let funds = info.funds.first();
-    if funds.is_none() {
-        return Err(ContractError::InsufficientFunds {});
+fn state_update_registration_fee(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    fee: Option<Asset>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
     }
+    config.registration_fee = fee
+        .map(|fee| validate_asset(deps.api, &config, fee))
+        .transpose()?;
+    CONFIG.save(deps.storage, &config)?;
 
-    if funds.unwrap().amount.lt(&amount_to_stake_per_validator) {
-        return Err(ContractError::InsufficientFunds {});
+    let fee = config
+        .registration_fee
+        .map_or_else(|| "none".to_string(), |fee| fee.to_string());
+    Ok(Response::new()
+        .add_attribute("method", "update_registration_fee")
+        .add_attribute("fee", fee))
+}
+
+/// Entry point for CW20 `Send`, `info.sender` is the token contract and the
+/// tokens received pay for the wrapped action on behalf of `wrapper.sender`.
+fn state_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if !config.accepts_token(&info.sender) {
+        return Err(ContractError::TokenNotAccepted {});
     }
+    let sender = deps.api.addr_validate(&wrapper.sender)?;
+    let payment = vec![Asset {
+        denom: Denom::Cw20(info.sender),
+        amount: wrapper.amount,
+    }];
 
-    let msg = StakingMsg::Delegate {
-        validator: validator_addr.to_string(),
-        amount: Coin {
-            denom: vault_denom.clone(),
-            amount: amount_to_stake_per_validator,
-        },
-    };
+    match from_binary(&wrapper.msg)? {
+        ReceiveMsg::PushRealEstateToBlockchain {
+            parcel_id,
+            house_address,
+            area,
+            land_use,
+            coordinates,
+            owner,
+        } => state_push_realestate_to_blockchain(
+            deps,
+            env,
+            sender,
+            payment,
+            ParcelInfo {
+                parcel_id,
+                house_address,
+                area,
+                land_use,
+                coordinates,
+            },
+            owner,
+        ),
+        ReceiveMsg::DepositSaleFunds { house_index } => {
+            state_deposit_sale_funds(deps, env, sender, payment, house_index)
+        }
+        ReceiveMsg::PayPropertyTax { house_index } => {
+            state_pay_property_tax(deps, env, sender, payment, house_index)
+        }
+        ReceiveMsg::BidTaxSale { house_index } => {
+            state_bid_tax_sale(deps, env, sender, payment, house_index)
+        }
+        ReceiveMsg::AcceptLease {
+            house_index,
+            lease_id,
+        } => state_accept_lease(deps, env, sender, payment, house_index, lease_id),
+        ReceiveMsg::BuyListedProperty { house_index } => {
+            state_buy_listed_property(deps, env, sender, payment, house_index)
+        }
+        ReceiveMsg::MakeOffer { house_index } => {
+            state_make_offer(deps, env, sender, payment, house_index)
+        }
+        ReceiveMsg::AcceptOffer {
+            house_index,
+            offer_id,
+        } => state_accept_offer(deps, env, sender, payment, house_index, offer_id),
+        ReceiveMsg::PayRent {
+            house_index,
+            lease_id,
+        } => state_pay_rent(deps, env, sender, payment, house_index, lease_id),
+    }
+}
+
+fn state_push_realestate_to_blockchain(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    payment: Vec<Asset>,
+    parcel: ParcelInfo,
+    owner: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if !config.is_registrar(&sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let mut response = Response::new();
+    match &config.registration_fee {
+        Some(fee) => {
+            must_pay_exactly(&payment, fee)?;
+            response = response.add_message(send_asset(&config.admin, fee.clone())?);
+        }
+        None if !payment.is_empty() => return Err(ContractError::UnexpectedPayment {}),
+        None => {}
+    }
+    let owner = deps.api.addr_validate(&owner)?;
+    let property = register_property(deps.storage, &env, parcel, owner, vec![])?;
+    let request = open_registration(
+        deps.storage,
+        &env,
+        &config,
+        sender,
+        vec![property.house_index],
+        vec![],
+        None,
+    )?;
+
+    Ok(response
+        .add_attribute("method", "push_real_estate_to_blockchain")
+        .add_attribute("house_index", property.house_index.to_string())
+        .add_attribute("status", request.status.as_str())
+        .add_attribute("approvals", request.approvals.len().to_string()))
+}
+
+fn state_approve_registration(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let (config, mut request) = load_open_registration(deps.as_ref(), &env, &info, house_index)?;
+    request.approvals.push(info.sender);
+    let request = tally_registration(deps.storage, &env, &config, request)?;
 
     Ok(Response::new()
-        .add_messages([msg])
-        .add_attribute("method", "add_validator"))
+        .add_attribute("method", "approve_registration")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("status", request.status.as_str())
+        .add_attribute("approvals", request.approvals.len().to_string()))
 }
 
-fn state_staking_delegate(
-    _deps: DepsMut,
-    _env: Env,
-    _info: MessageInfo,
-    validator_addr: Addr,
-    vault_denom: String,
-    amount_to_delegate: u64,
+fn state_reject_registration(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
 ) -> Result<Response, ContractError> {
-    let msg = StakingMsg::Delegate {
-        validator: validator_addr.to_string(),
-        amount: Coin {
-            denom: vault_denom.clone(),
-            amount: Uint128::new(amount_to_delegate.into()),
-        },
-    };
-    Ok(Response::new().add_messages([msg]))
+    let (config, mut request) = load_open_registration(deps.as_ref(), &env, &info, house_index)?;
+    request.rejections.push(info.sender);
+    let request = tally_registration(deps.storage, &env, &config, request)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "reject_registration")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("status", request.status.as_str())
+        .add_attribute("rejections", request.rejections.len().to_string()))
 }
 
-fn state_staking_undelegate(
-    _deps: DepsMut,
-    _env: Env,
+fn state_expire_registration(
+    deps: DepsMut,
+    env: Env,
     _info: MessageInfo,
-    validator_addr: Addr,
-    vault_denom: String,
-    amount_to_delegate: u64,
+    house_index: u64,
 ) -> Result<Response, ContractError> {
-    let msg = StakingMsg::Undelegate {
-        validator: validator_addr.to_string(),
-        amount: Coin {
-            denom: vault_denom.clone(),
-            amount: Uint128::new(amount_to_delegate.into()),
-        },
-    };
-    Ok(Response::new().add_messages([msg]))
+    let mut request = REGISTRATION_REQUESTS
+        .may_load(deps.storage, house_index.into())?
+        .filter(|request| request.status == RegistrationStatus::Open)
+        .ok_or(ContractError::NoOpenRegistration {})?;
+    if env.block.time < request.expires {
+        return Err(ContractError::RegistrationNotExpired {});
+    }
+
+    request.status = RegistrationStatus::Expired;
+    discard_records(deps.storage, &request)?;
+    REGISTRATION_REQUESTS.save(deps.storage, house_index.into(), &request)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "expire_registration")
+        .add_attribute("house_index", house_index.to_string()))
 }
 
-fn  state_num_save(
+fn state_update_registration_policy(
     deps: DepsMut,
     _env: Env,
-    _info: MessageInfo,
-    num_to_save_start:u64,
-    num_to_save_end: u64,
+    info: MessageInfo,
+    approval_threshold: u32,
+    registration_ttl: u64,
 ) -> Result<Response, ContractError> {
-    let mut state = STATE.load(deps.storage)?;
-    for i in (num_to_save_start)..(num_to_save_end+1)
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    config.approval_threshold = approval_threshold;
+    config.registration_ttl = registration_ttl;
+    if !config.has_valid_threshold() {
+        return Err(ContractError::InvalidThreshold {});
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_registration_policy")
+        .add_attribute("approval_threshold", approval_threshold.to_string())
+        .add_attribute("registration_ttl", registration_ttl.to_string()))
+}
+
+fn load_open_registration(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    house_index: u64,
+) -> Result<(Config, RegistrationRequest), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if !config.is_registrar(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let request = REGISTRATION_REQUESTS
+        .may_load(deps.storage, house_index.into())?
+        .filter(|request| request.status == RegistrationStatus::Open)
+        .ok_or(ContractError::NoOpenRegistration {})?;
+    if env.block.time >= request.expires {
+        return Err(ContractError::RegistrationExpired {});
+    }
+    if request.has_voted(&info.sender) {
+        return Err(ContractError::AlreadyVoted {});
+    }
+    Ok((config, request))
+}
+
+/// Opens the approval round for freshly registered pending `records`. A
+/// registrar proposing the change casts the first approval.
+fn open_registration(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    proposer: Addr,
+    records: Vec<u64>,
+    retires: Vec<u64>,
+    memo: Option<String>,
+) -> Result<RegistrationRequest, ContractError> {
+    let approvals = if config.is_registrar(&proposer) {
+        vec![proposer.clone()]
+    } else {
+        vec![]
+    };
+    let request = RegistrationRequest {
+        house_index: records[0],
+        records,
+        retires,
+        memo,
+        proposer,
+        approvals,
+        rejections: vec![],
+        submitted_at: env.block.time,
+        expires: env.block.time.plus_seconds(config.registration_ttl),
+        status: RegistrationStatus::Open,
+    };
+    tally_registration(storage, env, config, request)
+}
+
+/// Brings the records into force once enough registrars approved them, and
+/// drops them once the remaining registrars can no longer reach the threshold.
+/// Only approvals from registrars still in office are counted.
+fn tally_registration(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    mut request: RegistrationRequest,
+) -> Result<RegistrationRequest, ContractError> {
+    let threshold = config.approval_threshold as usize;
+    let approvals = request
+        .approvals
+        .iter()
+        .filter(|registrar| config.is_registrar(registrar))
+        .count();
+    let undecided = config
+        .registrars
+        .iter()
+        .filter(|registrar| !request.has_voted(registrar))
+        .count();
+    if approvals >= threshold {
+        request.status = RegistrationStatus::Approved;
+        activate_records(storage, env, &request)?;
+    } else if approvals + undecided < threshold {
+        request.status = RegistrationStatus::Rejected;
+        discard_records(storage, &request)?;
+    }
+    REGISTRATION_REQUESTS.save(storage, request.house_index.into(), &request)?;
+    Ok(request)
+}
+
+/// Starts the chain of title of every approved record and retires the titles
+/// they replace, carrying the encumbrances over to the successors.
+fn activate_records(
+    storage: &mut dyn Storage,
+    env: &Env,
+    request: &RegistrationRequest,
+) -> Result<(), ContractError> {
+    let mut successors = vec![];
+    for house_index in &request.records {
+        let mut property = load_property(storage, *house_index)?;
+        change_status(storage, &mut property, PropertyStatus::Active)?;
+        properties().save(storage, property.house_index.into(), &property)?;
+        append_title_history(
+            storage,
+            env,
+            property.house_index,
+            None,
+            property.owner.clone(),
+            request.memo.clone(),
+        )?;
+        successors.push(property);
+    }
+    for house_index in &request.retires {
+        let mut predecessor = load_property(storage, *house_index)?;
+        if successors
+            .iter()
+            .any(|successor| successor.owner != predecessor.owner)
+        {
+            return Err(ContractError::OwnerMismatch {});
+        }
+        ensure_no_active_liens(storage, *house_index)?;
+        // easements and covenants run with the land into every successor
+        for successor in &successors {
+            carry_encumbrances(
+                storage,
+                env,
+                *house_index,
+                successor.house_index,
+                &request.retires,
+            )?;
+        }
+        change_status(storage, &mut predecessor, PropertyStatus::Retired)?;
+        properties().save(storage, predecessor.house_index.into(), &predecessor)?;
+    }
+    carry_tax_accounts(storage, env, &request.retires, &successors)?;
+    Ok(())
+}
+
+/// Removes the records of a failed request and unlocks the titles it would
+/// have retired.
+fn discard_records(storage: &mut dyn Storage, request: &RegistrationRequest) -> StdResult<()> {
+    for house_index in &request.records {
+        properties().remove(storage, (*house_index).into())?;
+    }
+    for house_index in &request.retires {
+        if let Some(mut predecessor) = properties().may_load(storage, (*house_index).into())? {
+            predecessor.children.clear();
+            properties().save(storage, predecessor.house_index.into(), &predecessor)?;
+        }
+    }
+    Ok(())
+}
+
+/// Validates a parcel and stores it as a new pending title under the next house
+/// index. The chain of title starts once its registration is approved.
+fn register_property(
+    storage: &mut dyn Storage,
+    env: &Env,
+    parcel: ParcelInfo,
+    owner: Addr,
+    parents: Vec<u64>,
+) -> Result<Property, ContractError> {
+    if parcel.area == 0 {
+        return Err(ContractError::InvalidArea {});
+    }
+    if parcel.coordinates.latitude.abs() > MAX_LATITUDE
+        || parcel.coordinates.longitude.abs() > MAX_LONGITUDE
+    {
+        return Err(ContractError::InvalidCoordinates {});
+    }
+    if properties()
+        .idx
+        .house_address
+        .item(storage, parcel.house_address.clone())?
+        .is_some()
+    {
+        return Err(ContractError::RealEstateAlreadyExists {});
+    }
+    if properties()
+        .idx
+        .parcel_id
+        .item(storage, parcel.parcel_id.clone())?
+        .is_some()
     {
-        state.state_num = i;
+        return Err(ContractError::ParcelAlreadyExists {});
     }
-    STATE.save(deps.storage, &state);
-    Ok(Response::default())
+
+    let mut state = STATE.load(storage)?;
+    state.num_of_real_state += 1;
+    let house_index = state.num_of_real_state;
+    STATE.save(storage, &state)?;
+
+    let property = Property {
+        house_index,
+        parcel_id: parcel.parcel_id,
+        house_address: parcel.house_address,
+        area: parcel.area,
+        land_use: parcel.land_use,
+        coordinates: parcel.coordinates,
+        registered_at_height: env.block.height,
+        registered_at: env.block.time,
+        owner,
+        parents,
+        children: vec![],
+        status: PropertyStatus::Pending,
+    };
+    properties().save(storage, house_index.into(), &property)?;
+
+    Ok(property)
 }
 
-fn state_withdraw_rewards(
-    _deps: DepsMut,
-    _env: Env,
-    _info: MessageInfo,
-    validator_addr: Addr,
+fn state_subdivide_property(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    children: Vec<ParcelInfo>,
 ) -> Result<Response, ContractError> {
-    // make sure the validator is added first
-    let msg = DistributionMsg::WithdrawDelegatorReward {
-        validator: validator_addr.to_string(),
+    let mut parent = load_property(deps.storage, house_index)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != parent.owner && !config.is_registrar(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_transferable(deps.storage, &env, &parent)?;
+    if PENDING_TRANSFERS.has(deps.storage, house_index.into()) {
+        return Err(ContractError::TransferAlreadyPending {});
+    }
+    ensure_no_active_liens(deps.storage, house_index)?;
+    if children.len() < 2 {
+        return Err(ContractError::TooFewChildParcels {});
+    }
+    let total_area = children
+        .iter()
+        .fold(0u64, |total, child| total.saturating_add(child.area));
+    if total_area != parent.area {
+        return Err(ContractError::AreaMismatch {
+            expected: parent.area,
+            actual: total_area,
+        });
+    }
+
+    // the parent's own address and parcel id stay reserved by the retired record
+    for child in children {
+        let child = register_property(
+            deps.storage,
+            &env,
+            child,
+            parent.owner.clone(),
+            vec![house_index],
+        )?;
+        parent.children.push(child.house_index);
+    }
+    // the parent stays in force, but locked, until the registrars approve
+    properties().save(deps.storage, house_index.into(), &parent)?;
+    let request = open_registration(
+        deps.storage,
+        &env,
+        &config,
+        info.sender,
+        parent.children.clone(),
+        vec![house_index],
+        Some(format!("subdivided from {}", house_index)),
+    )?;
+
+    let children = parent
+        .children
+        .iter()
+        .map(|child| child.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    Ok(Response::new()
+        .add_attribute("method", "subdivide_property")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("children", children)
+        .add_attribute("status", request.status.as_str()))
+}
+
+fn state_merge_properties(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    indices: Vec<u64>,
+    merged: ParcelInfo,
+) -> Result<Response, ContractError> {
+    let mut indices = indices;
+    indices.sort_unstable();
+    indices.dedup();
+    if indices.len() < 2 {
+        return Err(ContractError::TooFewParcelsToMerge {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let mut predecessors = vec![];
+    for house_index in &indices {
+        let property = load_property(deps.storage, *house_index)?;
+        if predecessors
+            .first()
+            .map_or(false, |first: &Property| first.owner != property.owner)
+        {
+            return Err(ContractError::OwnerMismatch {});
+        }
+        if property.owner != info.sender && !config.is_registrar(&info.sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+        ensure_transferable(deps.storage, &env, &property)?;
+        if PENDING_TRANSFERS.has(deps.storage, (*house_index).into()) {
+            return Err(ContractError::TransferAlreadyPending {});
+        }
+        ensure_no_active_liens(deps.storage, *house_index)?;
+        predecessors.push(property);
+    }
+    let total_area = predecessors
+        .iter()
+        .fold(0u64, |total, property| total.saturating_add(property.area));
+    if merged.area != total_area {
+        return Err(ContractError::AreaMismatch {
+            expected: total_area,
+            actual: merged.area,
+        });
+    }
+
+    let owner = predecessors[0].owner.clone();
+    let property = register_property(deps.storage, &env, merged, owner, indices.clone())?;
+    // the predecessors stay in force, but locked, until the registrars approve
+    for mut predecessor in predecessors {
+        predecessor.children.push(property.house_index);
+        properties().save(deps.storage, predecessor.house_index.into(), &predecessor)?;
+    }
+    let memo = Some(format!(
+        "merged from {}",
+        indices
+            .iter()
+            .map(|house_index| house_index.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    ));
+    let request = open_registration(
+        deps.storage,
+        &env,
+        &config,
+        info.sender,
+        vec![property.house_index],
+        indices,
+        memo,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "merge_properties")
+        .add_attribute("house_index", property.house_index.to_string())
+        .add_attribute("status", request.status.as_str()))
+}
+
+fn state_change_property_status(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    status: PropertyStatus,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if !config.is_registrar(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let mut property = load_property(deps.storage, house_index)?;
+    // retirement only happens through subdivision and merger, disputes go through
+    // the arbitrators and pending records through the registration approvals
+    if status == PropertyStatus::Retired
+        || status == PropertyStatus::UnderDispute
+        || property.status == PropertyStatus::UnderDispute
+        || property.status == PropertyStatus::Pending
+    {
+        return Err(ContractError::InvalidStatusTransition {
+            from: property.status,
+            to: status,
+        });
+    }
+    change_status(deps.storage, &mut property, status)?;
+    properties().save(deps.storage, house_index.into(), &property)?;
+    if status == PropertyStatus::Demolished {
+        close_tax_account(deps.storage, &env, house_index)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "change_property_status")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("status", status.as_str()))
+}
+
+fn state_freeze_property(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    reason: String,
+    case_ref: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if !config.is_arbitrator(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let mut property = load_property(deps.storage, house_index)?;
+    let prior_status = property.status;
+    change_status(deps.storage, &mut property, PropertyStatus::UnderDispute)?;
+    properties().save(deps.storage, house_index.into(), &property)?;
+
+    let dispute_id = DISPUTES
+        .prefix(house_index.into())
+        .range(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(1, |(_, dispute)| dispute.dispute_id + 1);
+    let dispute = Dispute {
+        dispute_id,
+        house_index,
+        reason,
+        case_ref,
+        frozen_by: info.sender,
+        frozen_at: env.block.time,
+        prior_status,
+        outcome: None,
+        resolved_by: None,
+        resolved_at: None,
     };
-    Ok(Response::new().add_messages([msg]))
+    DISPUTES.save(
+        deps.storage,
+        (house_index.into(), dispute_id.into()),
+        &dispute,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "freeze_property")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("dispute_id", dispute_id.to_string())
+        .add_attribute("case_ref", dispute.case_ref))
+}
+
+fn state_resolve_dispute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    outcome: DisputeOutcome,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if !config.is_arbitrator(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let mut property = load_property(deps.storage, house_index)?;
+    let (_, mut dispute) = DISPUTES
+        .prefix(house_index.into())
+        .range(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .filter(|(_, dispute)| dispute.outcome.is_none())
+        .ok_or(ContractError::NoOpenDispute {})?;
+    change_status(deps.storage, &mut property, dispute.prior_status)?;
+    properties().save(deps.storage, house_index.into(), &property)?;
+
+    let mut response = Response::new()
+        .add_attribute("method", "resolve_dispute")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("dispute_id", dispute.dispute_id.to_string());
+    let outcome = match outcome {
+        DisputeOutcome::OwnerConfirmed => DisputeOutcome::OwnerConfirmed,
+        DisputeOutcome::TitleAwarded { new_owner } => {
+            let new_owner = deps.api.addr_validate(new_owner.as_str())?;
+            // a court order overrides any sale the losing party had arranged
+            if let Some(sale) = SALES.may_load(deps.storage, house_index.into())? {
+                SALES.remove(deps.storage, house_index.into());
+                if let (true, Some(buyer)) = (sale.funded, sale.buyer) {
+                    response = response.add_message(send_asset(&buyer, sale.price)?);
+                }
+            }
+            if new_owner != property.owner {
+                let refunds = transfer_title(
+                    deps.storage,
+                    &env,
+                    &mut property,
+                    new_owner.clone(),
+                    Some(format!("court order {}", dispute.case_ref)),
+                )?;
+                response = response.add_messages(refunds);
+            }
+            response = response.add_attribute("new_owner", new_owner.clone());
+            DisputeOutcome::TitleAwarded { new_owner }
+        }
+    };
+
+    dispute.outcome = Some(outcome);
+    dispute.resolved_by = Some(info.sender);
+    dispute.resolved_at = Some(env.block.time);
+    DISPUTES.save(
+        deps.storage,
+        (house_index.into(), dispute.dispute_id.into()),
+        &dispute,
+    )?;
+
+    Ok(response)
+}
+
+fn state_change_owner_of_real_estate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_address: String,
+    new_owner: String,
+    memo: Option<String>,
+) -> Result<Response, ContractError> {
+    let (_, mut property) = properties()
+        .idx
+        .house_address
+        .item(deps.storage, house_address)?
+        .ok_or(ContractError::RealEstateDoesNotExist {})?;
+    // owners hand over title with ProposeTransfer, this is for registry corrections
+    let config = CONFIG.load(deps.storage)?;
+    if !config.is_registrar(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_transferable(deps.storage, &env, &property)?;
+
+    let new_owner = deps.api.addr_validate(&new_owner)?;
+    let refunds = transfer_title(deps.storage, &env, &mut property, new_owner, memo)?;
+
+    Ok(Response::new()
+        .add_messages(refunds)
+        .add_attribute("method", "change_owner_of_real_estate")
+        .add_attribute("house_index", property.house_index.to_string())
+        .add_attribute("new_owner", property.owner))
+}
+
+fn state_propose_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    recipient: String,
+    expires: Option<Timestamp>,
+    memo: Option<String>,
+) -> Result<Response, ContractError> {
+    let property = load_property(deps.storage, house_index)?;
+    if info.sender != property.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_transferable(deps.storage, &env, &property)?;
+    if PENDING_TRANSFERS.has(deps.storage, house_index.into()) {
+        return Err(ContractError::TransferAlreadyPending {});
+    }
+    if expires.map_or(false, |expires| expires <= env.block.time) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+    let recipient = deps.api.addr_validate(&recipient)?;
+    if recipient == property.owner {
+        return Err(ContractError::TransferToSelf {});
+    }
+
+    let pending = PendingTransfer {
+        house_index,
+        from: property.owner,
+        to: recipient,
+        proposed_at: env.block.time,
+        expires,
+        memo,
+    };
+    PENDING_TRANSFERS.save(deps.storage, house_index.into(), &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "propose_transfer")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("recipient", pending.to))
+}
+
+fn state_accept_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_TRANSFERS
+        .may_load(deps.storage, house_index.into())?
+        .ok_or(ContractError::NoPendingTransfer {})?;
+    if info.sender != pending.to {
+        return Err(ContractError::Unauthorized {});
+    }
+    if pending.is_expired(env.block.time) {
+        return Err(ContractError::TransferExpired {});
+    }
+
+    let mut property = load_property(deps.storage, house_index)?;
+    ensure_transferable(deps.storage, &env, &property)?;
+    let refunds = transfer_title(deps.storage, &env, &mut property, pending.to, pending.memo)?;
+
+    Ok(Response::new()
+        .add_messages(refunds)
+        .add_attribute("method", "accept_transfer")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("new_owner", property.owner))
+}
+
+fn state_cancel_transfer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_TRANSFERS
+        .may_load(deps.storage, house_index.into())?
+        .ok_or(ContractError::NoPendingTransfer {})?;
+    if info.sender != pending.from {
+        return Err(ContractError::Unauthorized {});
+    }
+    PENDING_TRANSFERS.remove(deps.storage, house_index.into());
+
+    Ok(Response::new()
+        .add_attribute("method", "cancel_transfer")
+        .add_attribute("house_index", house_index.to_string()))
+}
+
+fn state_reject_transfer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_TRANSFERS
+        .may_load(deps.storage, house_index.into())?
+        .ok_or(ContractError::NoPendingTransfer {})?;
+    if info.sender != pending.to {
+        return Err(ContractError::Unauthorized {});
+    }
+    PENDING_TRANSFERS.remove(deps.storage, house_index.into());
+
+    Ok(Response::new()
+        .add_attribute("method", "reject_transfer")
+        .add_attribute("house_index", house_index.to_string()))
+}
+
+fn state_transfer_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let mut property = load_token(deps.as_ref(), &token_id)?;
+    ensure_can_send(deps.as_ref(), &env, &info, &property)?;
+    ensure_transferable(deps.storage, &env, &property)?;
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let refunds = transfer_title(deps.storage, &env, &mut property, recipient, None)?;
+
+    Ok(Response::new()
+        .add_messages(refunds)
+        .add_attribute("method", "transfer_nft")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", property.owner)
+        .add_attribute("token_id", token_id))
+}
+
+fn state_send_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    contract: String,
+    token_id: String,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let mut property = load_token(deps.as_ref(), &token_id)?;
+    ensure_can_send(deps.as_ref(), &env, &info, &property)?;
+    ensure_transferable(deps.storage, &env, &property)?;
+
+    let contract = deps.api.addr_validate(&contract)?;
+    let refunds = transfer_title(deps.storage, &env, &mut property, contract.clone(), None)?;
+
+    let receive = Cw721ReceiveMsg {
+        sender: info.sender.to_string(),
+        token_id: token_id.clone(),
+        msg,
+    };
+    Ok(Response::new()
+        .add_messages(refunds)
+        .add_message(receive.into_cosmos_msg(contract.to_string())?)
+        .add_attribute("method", "send_nft")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", contract)
+        .add_attribute("token_id", token_id))
+}
+
+fn state_approve(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    token_id: String,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let property = load_token(deps.as_ref(), &token_id)?;
+    ensure_can_approve(deps.as_ref(), &env, &info, &property)?;
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::Expired {});
+    }
+
+    let spender = deps.api.addr_validate(&spender)?;
+    let key = U64Key::from(property.house_index);
+    let mut approvals = NFT_APPROVALS
+        .may_load(deps.storage, key.clone())?
+        .unwrap_or_default();
+    approvals.retain(|approval| approval.spender != spender);
+    approvals.push(NftApproval {
+        spender: spender.clone(),
+        expires,
+    });
+    NFT_APPROVALS.save(deps.storage, key, &approvals)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "approve")
+        .add_attribute("sender", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("token_id", token_id))
+}
+
+fn state_revoke(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let property = load_token(deps.as_ref(), &token_id)?;
+    ensure_can_approve(deps.as_ref(), &env, &info, &property)?;
+
+    let spender = deps.api.addr_validate(&spender)?;
+    let key = U64Key::from(property.house_index);
+    let mut approvals = NFT_APPROVALS
+        .may_load(deps.storage, key.clone())?
+        .unwrap_or_default();
+    let before = approvals.len();
+    approvals.retain(|approval| approval.spender != spender);
+    if approvals.len() == before {
+        return Err(ContractError::ApprovalDoesNotExist {});
+    }
+    if approvals.is_empty() {
+        NFT_APPROVALS.remove(deps.storage, key);
+    } else {
+        NFT_APPROVALS.save(deps.storage, key, &approvals)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "revoke")
+        .add_attribute("sender", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("token_id", token_id))
+}
+
+fn state_approve_all(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    operator: String,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::Expired {});
+    }
+    let operator = deps.api.addr_validate(&operator)?;
+    NFT_OPERATORS.save(deps.storage, (&info.sender, &operator), &expires)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "approve_all")
+        .add_attribute("sender", info.sender)
+        .add_attribute("operator", operator))
+}
+
+fn state_revoke_all(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operator: String,
+) -> Result<Response, ContractError> {
+    let operator = deps.api.addr_validate(&operator)?;
+    if !NFT_OPERATORS.has(deps.storage, (&info.sender, &operator)) {
+        return Err(ContractError::ApprovalDoesNotExist {});
+    }
+    NFT_OPERATORS.remove(deps.storage, (&info.sender, &operator));
+
+    Ok(Response::new()
+        .add_attribute("method", "revoke_all")
+        .add_attribute("sender", info.sender)
+        .add_attribute("operator", operator))
+}
+
+/// Title tokens are identified by the decimal house index.
+fn parse_token_id(token_id: &str) -> StdResult<u64> {
+    token_id
+        .parse()
+        .map_err(|_| StdError::generic_err(format!("Invalid token id: {}", token_id)))
+}
+
+/// Loads the property behind a title token, records that are not in force
+/// have no token.
+fn load_token(deps: Deps, token_id: &str) -> StdResult<Property> {
+    let property = load_property_by_house_index(deps, parse_token_id(token_id)?)?;
+    if !property.status.is_token() {
+        return Err(StdError::not_found("title token"));
+    }
+    Ok(property)
+}
+
+fn is_operator(deps: Deps, env: &Env, owner: &Addr, operator: &Addr) -> StdResult<bool> {
+    Ok(NFT_OPERATORS
+        .may_load(deps.storage, (owner, operator))?
+        .map_or(false, |expires| !expires.is_expired(&env.block)))
+}
+
+/// The owner and its operators may grant or revoke approvals on a title token.
+fn ensure_can_approve(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    property: &Property,
+) -> Result<(), ContractError> {
+    if info.sender == property.owner || is_operator(deps, env, &property.owner, &info.sender)? {
+        return Ok(());
+    }
+    Err(ContractError::Unauthorized {})
+}
+
+/// Approved spenders may additionally move the token itself.
+fn ensure_can_send(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    property: &Property,
+) -> Result<(), ContractError> {
+    if ensure_can_approve(deps, env, info, property).is_ok() {
+        return Ok(());
+    }
+    let approved = NFT_APPROVALS
+        .may_load(deps.storage, property.house_index.into())?
+        .unwrap_or_default()
+        .into_iter()
+        .any(|approval| {
+            approval.spender == info.sender && !approval.expires.is_expired(&env.block)
+        });
+    if approved {
+        return Ok(());
+    }
+    Err(ContractError::Unauthorized {})
+}
+
+fn state_open_sale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    price: Uint128,
+    denom: Denom,
+    buyer: Option<String>,
+) -> Result<Response, ContractError> {
+    let property = load_property(deps.storage, house_index)?;
+    if info.sender != property.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_transferable(deps.storage, &env, &property)?;
+    if PENDING_TRANSFERS.has(deps.storage, house_index.into()) {
+        return Err(ContractError::TransferAlreadyPending {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let price = validate_asset(
+        deps.api,
+        &config,
+        Asset {
+            denom,
+            amount: price,
+        },
+    )?;
+    let buyer = buyer
+        .map(|buyer| deps.api.addr_validate(&buyer))
+        .transpose()?;
+    if buyer.as_ref() == Some(&property.owner) {
+        return Err(ContractError::TransferToSelf {});
+    }
+
+    let sale = Sale {
+        house_index,
+        seller: property.owner,
+        buyer,
+        price,
+        funded: false,
+    };
+    SALES.save(deps.storage, house_index.into(), &sale)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "open_sale")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("price", sale.price.to_string()))
+}
+
+fn state_deposit_sale_funds(
+    deps: DepsMut,
+    _env: Env,
+    sender: Addr,
+    payment: Vec<Asset>,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let mut sale = SALES
+        .may_load(deps.storage, house_index.into())?
+        .ok_or(ContractError::NoSaleInProgress {})?;
+    if sale.funded {
+        return Err(ContractError::SaleAlreadyFunded {});
+    }
+    ensure_active(&load_property(deps.storage, house_index)?)?;
+    if sender == sale.seller {
+        return Err(ContractError::TransferToSelf {});
+    }
+    if sale.buyer.as_ref().map_or(false, |buyer| buyer != &sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    must_pay_exactly(&payment, &sale.price)?;
+
+    sale.buyer = Some(sender.clone());
+    sale.funded = true;
+    SALES.save(deps.storage, house_index.into(), &sale)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "deposit_sale_funds")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("buyer", sender))
+}
+
+fn state_close_sale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let sale = SALES
+        .may_load(deps.storage, house_index.into())?
+        .ok_or(ContractError::NoSaleInProgress {})?;
+    if info.sender != sale.seller {
+        return Err(ContractError::Unauthorized {});
+    }
+    let buyer = match (sale.funded, sale.buyer) {
+        (true, Some(buyer)) => buyer,
+        _ => return Err(ContractError::SaleNotFunded {}),
+    };
+
+    SALES.remove(deps.storage, house_index.into());
+    let mut property = load_property(deps.storage, house_index)?;
+    ensure_transferable(deps.storage, &env, &property)?;
+    let refunds = transfer_title(
+        deps.storage,
+        &env,
+        &mut property,
+        buyer,
+        Some(format!("sale for {}", sale.price)),
+    )?;
+
+    Ok(Response::new()
+        .add_message(send_asset(&sale.seller, sale.price)?)
+        .add_messages(refunds)
+        .add_attribute("method", "close_sale")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("new_owner", property.owner))
+}
+
+fn state_cancel_sale(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let sale = SALES
+        .may_load(deps.storage, house_index.into())?
+        .ok_or(ContractError::NoSaleInProgress {})?;
+    let is_buyer = sale.funded && sale.buyer.as_ref() == Some(&info.sender);
+    if info.sender != sale.seller && !is_buyer {
+        return Err(ContractError::Unauthorized {});
+    }
+    SALES.remove(deps.storage, house_index.into());
+
+    let mut response = Response::new()
+        .add_attribute("method", "cancel_sale")
+        .add_attribute("house_index", house_index.to_string());
+    // refund the escrowed payment
+    if let (true, Some(buyer)) = (sale.funded, sale.buyer) {
+        response = response.add_message(send_asset(&buyer, sale.price)?);
+    }
+    Ok(response)
+}
+
+fn state_list_for_sale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    price: Asset,
+    expires: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    let property = load_property(deps.storage, house_index)?;
+    if info.sender != property.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_transferable(deps.storage, &env, &property)?;
+    if expires.map_or(false, |expires| expires <= env.block.time) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+
+    // listing again updates the asking price
+    let listing = Listing {
+        house_index,
+        seller: property.owner,
+        price: validate_asset(deps.api, &config, price)?,
+        listed_at: env.block.time,
+        expires,
+    };
+    LISTINGS.save(deps.storage, house_index.into(), &listing)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "list_for_sale")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("price", listing.price.to_string()))
+}
+
+fn state_delist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let listing = LISTINGS
+        .may_load(deps.storage, house_index.into())?
+        .ok_or(ContractError::NotListed {})?;
+    // expired listings are cleared by whoever comes across them, so they do
+    // not pile up in front of the live ones
+    if info.sender != listing.seller && !listing.is_expired(env.block.time) {
+        return Err(ContractError::Unauthorized {});
+    }
+    LISTINGS.remove(deps.storage, house_index.into());
+
+    Ok(Response::new()
+        .add_attribute("method", "delist")
+        .add_attribute("house_index", house_index.to_string()))
+}
+
+fn state_buy_listed_property(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    payment: Vec<Asset>,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let listing = LISTINGS
+        .may_load(deps.storage, house_index.into())?
+        .ok_or(ContractError::NotListed {})?;
+    if listing.is_expired(env.block.time) {
+        return Err(ContractError::ListingExpired {});
+    }
+    if sender == listing.seller {
+        return Err(ContractError::TransferToSelf {});
+    }
+    let mut property = load_property(deps.storage, house_index)?;
+    ensure_transferable(deps.storage, &env, &property)?;
+    must_pay_exactly(&payment, &listing.price)?;
+
+    let refunds = transfer_title(
+        deps.storage,
+        &env,
+        &mut property,
+        sender,
+        Some(format!("sale for {}", listing.price)),
+    )?;
+
+    Ok(Response::new()
+        .add_message(send_asset(&listing.seller, listing.price)?)
+        .add_messages(refunds)
+        .add_attribute("method", "buy_listed_property")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("new_owner", property.owner))
+}
+
+fn state_make_offer(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    payment: Vec<Asset>,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let property = load_property(deps.storage, house_index)?;
+    ensure_active(&property)?;
+    if sender == property.owner {
+        return Err(ContractError::TransferToSelf {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let amount = match payment.as_slice() {
+        [asset] => validate_asset(deps.api, &config, asset.clone())?,
+        _ => return Err(ContractError::InvalidPrice {}),
+    };
+    if let Some(minimum) = MINIMUM_OFFERS.may_load(deps.storage, house_index.into())? {
+        if amount.denom != minimum.denom || amount.amount < minimum.amount {
+            return Err(ContractError::OfferTooLow { minimum });
+        }
+    }
+
+    let offers = OFFERS
+        .prefix(house_index.into())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, offer)| offer))
+        .collect::<StdResult<Vec<_>>>()?;
+    let open = offers
+        .iter()
+        .filter(|offer| offer.status == OfferStatus::Open)
+        .count();
+    if open >= MAX_OPEN_OFFERS as usize {
+        return Err(ContractError::TooManyOffers {
+            max: MAX_OPEN_OFFERS,
+        });
+    }
+
+    let offer_id = offers.last().map_or(1, |offer| offer.offer_id + 1);
+    let offer = Offer {
+        offer_id,
+        house_index,
+        buyer: sender,
+        amount,
+        counter: None,
+        made_at: env.block.time,
+        status: OfferStatus::Open,
+    };
+    OFFERS.save(deps.storage, (house_index.into(), offer_id.into()), &offer)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "make_offer")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("offer_id", offer_id.to_string())
+        .add_attribute("amount", offer.amount.to_string()))
+}
+
+fn state_set_minimum_offer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    minimum: Option<Asset>,
+) -> Result<Response, ContractError> {
+    let property = load_property(deps.storage, house_index)?;
+    if info.sender != property.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+
+    let response = Response::new()
+        .add_attribute("method", "set_minimum_offer")
+        .add_attribute("house_index", house_index.to_string());
+    match minimum {
+        Some(minimum) => {
+            let minimum = validate_asset(deps.api, &config, minimum)?;
+            MINIMUM_OFFERS.save(deps.storage, house_index.into(), &minimum)?;
+            Ok(response.add_attribute("minimum", minimum.to_string()))
+        }
+        None => {
+            MINIMUM_OFFERS.remove(deps.storage, house_index.into());
+            Ok(response)
+        }
+    }
+}
+
+fn state_counter_offer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    offer_id: u64,
+    price: Uint128,
+) -> Result<Response, ContractError> {
+    let property = load_property(deps.storage, house_index)?;
+    if info.sender != property.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    let mut offer = load_open_offer(deps.storage, house_index, offer_id)?;
+    if price <= offer.amount.amount {
+        return Err(ContractError::InvalidCounterOffer {});
+    }
+    offer.counter = Some(price);
+    OFFERS.save(deps.storage, (house_index.into(), offer_id.into()), &offer)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "counter_offer")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("offer_id", offer_id.to_string())
+        .add_attribute("price", price))
+}
+
+/// Sells the property for the accepted offer and refunds every other open offer.
+fn state_accept_offer(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    payment: Vec<Asset>,
+    house_index: u64,
+    offer_id: u64,
+) -> Result<Response, ContractError> {
+    let mut property = load_property(deps.storage, house_index)?;
+    let mut offer = load_open_offer(deps.storage, house_index, offer_id)?;
+    let price = if sender == property.owner {
+        offer.amount.clone()
+    } else if sender == offer.buyer {
+        // the buyer can only take up a counter offer, paying the difference
+        let counter = offer.counter.ok_or(ContractError::Unauthorized {})?;
+        let difference = Asset {
+            denom: offer.amount.denom.clone(),
+            amount: counter - offer.amount.amount,
+        };
+        must_pay_exactly(&payment, &difference)?;
+        Asset {
+            denom: offer.amount.denom.clone(),
+            amount: counter,
+        }
+    } else {
+        return Err(ContractError::Unauthorized {});
+    };
+    ensure_transferable(deps.storage, &env, &property)?;
+
+    offer.status = OfferStatus::Accepted;
+    OFFERS.save(deps.storage, (house_index.into(), offer_id.into()), &offer)?;
+    let seller = property.owner.clone();
+    let refunds = transfer_title(
+        deps.storage,
+        &env,
+        &mut property,
+        offer.buyer,
+        Some(format!("offer accepted for {}", price)),
+    )?;
+
+    Ok(Response::new()
+        .add_message(send_asset(&seller, price)?)
+        .add_messages(refunds)
+        .add_attribute("method", "accept_offer")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("offer_id", offer_id.to_string())
+        .add_attribute("new_owner", property.owner))
+}
+
+fn state_withdraw_offer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    offer_id: u64,
+) -> Result<Response, ContractError> {
+    let mut offer = load_open_offer(deps.storage, house_index, offer_id)?;
+    if info.sender != offer.buyer {
+        return Err(ContractError::Unauthorized {});
+    }
+    offer.status = OfferStatus::Withdrawn;
+    OFFERS.save(deps.storage, (house_index.into(), offer_id.into()), &offer)?;
+
+    Ok(Response::new()
+        .add_message(send_asset(&offer.buyer, offer.amount)?)
+        .add_attribute("method", "withdraw_offer")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("offer_id", offer_id.to_string()))
+}
+
+fn load_open_offer(
+    storage: &dyn Storage,
+    house_index: u64,
+    offer_id: u64,
+) -> Result<Offer, ContractError> {
+    let offer = OFFERS
+        .may_load(storage, (house_index.into(), offer_id.into()))?
+        .ok_or(ContractError::OfferDoesNotExist {})?;
+    if offer.status != OfferStatus::Open {
+        return Err(ContractError::OfferNotOpen {});
+    }
+    Ok(offer)
+}
+
+fn state_register_lien(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    lienholder: String,
+    amount: Uint128,
+    denom: String,
+) -> Result<Response, ContractError> {
+    // a lien is granted by the owner or recorded by a registrar
+    let property = load_property(deps.storage, house_index)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != property.owner && !config.is_registrar(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_active(&property)?;
+    if amount.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+    let lienholder = deps.api.addr_validate(&lienholder)?;
+
+    let liens = load_liens(deps.storage, house_index)?;
+    let lien_id = liens.last().map_or(1, |lien| lien.lien_id + 1);
+    let priority = liens.iter().filter(|lien| lien.is_active()).count() as u32 + 1;
+    let lien = Lien {
+        lien_id,
+        house_index,
+        lienholder,
+        amount: Coin { denom, amount },
+        recorded_at: env.block.time,
+        priority,
+        released_at: None,
+        transfer_consent: false,
+    };
+    LIENS.save(deps.storage, (house_index.into(), lien_id.into()), &lien)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "register_lien")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("lien_id", lien_id.to_string())
+        .add_attribute("priority", priority.to_string()))
+}
+
+fn state_release_lien(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    lien_id: u64,
+) -> Result<Response, ContractError> {
+    let mut lien = load_lien(deps.storage, house_index, lien_id)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != lien.lienholder && !config.is_registrar(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !lien.is_active() {
+        return Err(ContractError::LienAlreadyReleased {});
+    }
+
+    lien.released_at = Some(env.block.time);
+    lien.transfer_consent = false;
+    LIENS.save(deps.storage, (house_index.into(), lien_id.into()), &lien)?;
+
+    // junior liens move up one rank
+    for mut junior in load_liens(deps.storage, house_index)? {
+        if junior.is_active() && junior.priority > lien.priority {
+            junior.priority -= 1;
+            LIENS.save(
+                deps.storage,
+                (house_index.into(), junior.lien_id.into()),
+                &junior,
+            )?;
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "release_lien")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("lien_id", lien_id.to_string()))
+}
+
+fn state_consent_to_transfer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    lien_id: u64,
+) -> Result<Response, ContractError> {
+    let mut lien = load_lien(deps.storage, house_index, lien_id)?;
+    if info.sender != lien.lienholder {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !lien.is_active() {
+        return Err(ContractError::LienAlreadyReleased {});
+    }
+
+    lien.transfer_consent = true;
+    LIENS.save(deps.storage, (house_index.into(), lien_id.into()), &lien)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "consent_to_transfer")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("lien_id", lien_id.to_string()))
+}
+
+fn load_lien(storage: &dyn Storage, house_index: u64, lien_id: u64) -> Result<Lien, ContractError> {
+    LIENS
+        .may_load(storage, (house_index.into(), lien_id.into()))?
+        .ok_or(ContractError::LienDoesNotExist {})
+}
+
+fn load_liens(storage: &dyn Storage, house_index: u64) -> StdResult<Vec<Lien>> {
+    LIENS
+        .prefix(house_index.into())
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, lien)| lien))
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn state_add_encumbrance(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    kind: EncumbranceKind,
+    beneficiary: Beneficiary,
+    terms: String,
+    document_hash: String,
+) -> Result<Response, ContractError> {
+    let property = load_property(deps.storage, house_index)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != property.owner && !config.is_registrar(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_active(&property)?;
+    validate_document_hash(&document_hash)?;
+    let beneficiary = match beneficiary {
+        Beneficiary::Parcel {
+            house_index: dominant,
+        } => {
+            if dominant == house_index {
+                return Err(ContractError::InvalidBeneficiary {});
+            }
+            load_property(deps.storage, dominant)?;
+            Beneficiary::Parcel {
+                house_index: dominant,
+            }
+        }
+        Beneficiary::Address { address } => Beneficiary::Address {
+            address: deps.api.addr_validate(address.as_str())?,
+        },
+    };
+
+    let encumbrance_id = ENCUMBRANCES
+        .prefix(house_index.into())
+        .range(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(1, |(_, encumbrance)| encumbrance.encumbrance_id + 1);
+    let encumbrance = Encumbrance {
+        encumbrance_id,
+        house_index,
+        kind,
+        beneficiary,
+        terms,
+        document_hash: document_hash.to_lowercase(),
+        recorded_at: env.block.time,
+        released_at: None,
+    };
+    ENCUMBRANCES.save(
+        deps.storage,
+        (house_index.into(), encumbrance_id.into()),
+        &encumbrance,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_encumbrance")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("encumbrance_id", encumbrance_id.to_string()))
+}
+
+fn state_release_encumbrance(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    encumbrance_id: u64,
+) -> Result<Response, ContractError> {
+    let key = (house_index.into(), encumbrance_id.into());
+    let mut encumbrance = ENCUMBRANCES
+        .may_load(deps.storage, key)?
+        .ok_or(ContractError::EncumbranceDoesNotExist {})?;
+    if encumbrance.released_at.is_some() {
+        return Err(ContractError::EncumbranceAlreadyReleased {});
+    }
+    // only the party holding the benefit, or a registrar, can give it up
+    let holder = match &encumbrance.beneficiary {
+        Beneficiary::Parcel { house_index } => load_property(deps.storage, *house_index)?.owner,
+        Beneficiary::Address { address } => address.clone(),
+    };
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != holder && !config.is_registrar(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    encumbrance.released_at = Some(env.block.time);
+    ENCUMBRANCES.save(
+        deps.storage,
+        (house_index.into(), encumbrance_id.into()),
+        &encumbrance,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "release_encumbrance")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("encumbrance_id", encumbrance_id.to_string()))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn state_anchor_document(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    sha256: String,
+    uri: String,
+    document_type: DocumentType,
+    transfer_sequence: Option<u64>,
+) -> Result<Response, ContractError> {
+    let property = load_property(deps.storage, house_index)?;
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != property.owner
+        && !config.is_registrar(&info.sender)
+        && !config.is_arbitrator(&info.sender)
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    validate_document_hash(&sha256)?;
+    let sha256 = sha256.to_lowercase();
+    if DOCUMENTS.has(deps.storage, (house_index.into(), sha256.clone())) {
+        return Err(ContractError::DocumentAlreadyAnchored {});
+    }
+    if let Some(sequence) = transfer_sequence {
+        if !TITLE_HISTORY.has(deps.storage, (house_index.into(), sequence.into())) {
+            return Err(ContractError::TitleTransferDoesNotExist {});
+        }
+    }
+
+    let document = Document {
+        house_index,
+        sha256,
+        uri,
+        document_type,
+        uploader: info.sender,
+        anchored_at: env.block.time,
+        transfer_sequence,
+    };
+    DOCUMENTS.save(
+        deps.storage,
+        (house_index.into(), document.sha256.clone()),
+        &document,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "anchor_document")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("sha256", document.sha256))
+}
+
+fn state_update_tax_policy(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    authority: String,
+    denom: Denom,
+    rates: Vec<TaxRate>,
+    grace_period: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let denom = validate_denom(deps.api, &config, denom)?;
+    // the tax already owed is counted in the current denom
+    if let Some(current) = TAX_POLICY.may_load(deps.storage)? {
+        let assessed = TAX_ACCOUNTS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .next()
+            .is_some();
+        if current.denom != denom && assessed {
+            return Err(ContractError::TaxDenomLocked {});
+        }
+    }
+    let policy = TaxPolicy {
+        authority: deps.api.addr_validate(&authority)?,
+        denom,
+        rates,
+        grace_period,
+    };
+    TAX_POLICY.save(deps.storage, &policy)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_tax_policy")
+        .add_attribute("authority", policy.authority))
+}
+
+fn state_assess_property(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    assessed_value: Uint128,
+) -> Result<Response, ContractError> {
+    let policy = load_tax_policy(deps.storage)?;
+    if info.sender != policy.authority {
+        return Err(ContractError::Unauthorized {});
+    }
+    let property = load_property(deps.storage, house_index)?;
+    if !property.status.is_token() {
+        return Err(ContractError::PropertyNotActive {
+            status: property.status,
+        });
+    }
+    let rate = policy.rate_for(property.land_use);
+
+    // tax accrued on the previous value and rate stays owed
+    let account = match TAX_ACCOUNTS.may_load(deps.storage, house_index.into())? {
+        Some(mut account) => {
+            account.accrue(env.block.time);
+            TaxAccount {
+                assessed_value,
+                assessed_at: env.block.time,
+                rate,
+                ..account
+            }
+        }
+        None => TaxAccount {
+            house_index,
+            assessed_value,
+            assessed_at: env.block.time,
+            arrears: Uint128::zero(),
+            accrued_until: env.block.time,
+            last_payment: None,
+            rate,
+        },
+    };
+    TAX_ACCOUNTS.save(deps.storage, house_index.into(), &account)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "assess_property")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("assessed_value", assessed_value))
+}
+
+fn state_pay_property_tax(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    payment: Vec<Asset>,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let policy = load_tax_policy(deps.storage)?;
+    load_property(deps.storage, house_index)?;
+    let mut account = TAX_ACCOUNTS
+        .may_load(deps.storage, house_index.into())?
+        .ok_or(ContractError::PropertyNotAssessed {})?;
+    let paid = match payment.as_slice() {
+        [asset] if asset.denom == policy.denom && !asset.amount.is_zero() => asset.clone(),
+        _ => return Err(ContractError::InvalidTaxPayment {}),
+    };
+
+    account.accrue(env.block.time);
+    account.arrears =
+        account
+            .arrears
+            .checked_sub(paid.amount)
+            .map_err(|_| ContractError::TaxOverpaid {
+                due: account.arrears,
+            })?;
+    account.last_payment = Some(env.block.time);
+    TAX_ACCOUNTS.save(deps.storage, house_index.into(), &account)?;
+
+    Ok(Response::new()
+        .add_message(send_asset(&policy.authority, paid.clone())?)
+        .add_attribute("method", "pay_property_tax")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("payer", sender)
+        .add_attribute("paid", paid.to_string())
+        .add_attribute("outstanding", account.arrears))
+}
+
+/// Splits the tax accounts of retired parcels over their successors by area,
+/// so neither the assessment nor the arrears go away with the old records.
+fn carry_tax_accounts(
+    storage: &mut dyn Storage,
+    env: &Env,
+    retired: &[u64],
+    successors: &[Property],
+) -> StdResult<()> {
+    let mut assessed_value = Uint128::zero();
+    let mut arrears = Uint128::zero();
+    let mut assessed = false;
+    for house_index in retired {
+        if let Some(mut account) = TAX_ACCOUNTS.may_load(storage, (*house_index).into())? {
+            account.accrue(env.block.time);
+            assessed_value += account.assessed_value;
+            arrears += account.arrears;
+            assessed = true;
+            TAX_ACCOUNTS.remove(storage, (*house_index).into());
+        }
+    }
+    if !assessed {
+        return Ok(());
+    }
+
+    let policy = TAX_POLICY.load(storage)?;
+    let total_area = successors.iter().fold(0u64, |total, successor| {
+        total.saturating_add(successor.area)
+    });
+    let mut value_left = assessed_value;
+    let mut arrears_left = arrears;
+    for (i, successor) in successors.iter().enumerate() {
+        // the last successor takes whatever the rounding left over
+        let (value, owed) = if i + 1 == successors.len() {
+            (value_left, arrears_left)
+        } else {
+            (
+                assessed_value.multiply_ratio(successor.area, total_area),
+                arrears.multiply_ratio(successor.area, total_area),
+            )
+        };
+        value_left -= value;
+        arrears_left -= owed;
+        let account = TaxAccount {
+            house_index: successor.house_index,
+            assessed_value: value,
+            assessed_at: env.block.time,
+            arrears: owed,
+            accrued_until: env.block.time,
+            last_payment: None,
+            rate: policy.rate_for(successor.land_use),
+        };
+        TAX_ACCOUNTS.save(storage, successor.house_index.into(), &account)?;
+    }
+    Ok(())
+}
+
+/// Stops the tax on a demolished record, arrears already owed stay payable.
+fn close_tax_account(storage: &mut dyn Storage, env: &Env, house_index: u64) -> StdResult<()> {
+    if let Some(mut account) = TAX_ACCOUNTS.may_load(storage, house_index.into())? {
+        account.accrue(env.block.time);
+        if account.arrears.is_zero() {
+            TAX_ACCOUNTS.remove(storage, house_index.into());
+        } else {
+            account.assessed_value = Uint128::zero();
+            TAX_ACCOUNTS.save(storage, house_index.into(), &account)?;
+        }
+    }
+    Ok(())
+}
+
+fn load_tax_policy(storage: &dyn Storage) -> Result<TaxPolicy, ContractError> {
+    TAX_POLICY
+        .may_load(storage)?
+        .ok_or(ContractError::TaxNotConfigured {})
+}
+
+/// A property is delinquent once part of its tax stays unpaid past the grace period.
+fn is_tax_delinquent(storage: &dyn Storage, env: &Env, property: &Property) -> StdResult<bool> {
+    let policy = match TAX_POLICY.may_load(storage)? {
+        Some(policy) => policy,
+        None => return Ok(false),
+    };
+    let account = TAX_ACCOUNTS.may_load(storage, property.house_index.into())?;
+    Ok(account.map_or(false, |account| {
+        !account
+            .overdue(env.block.time, policy.grace_period)
+            .is_zero()
+    }))
+}
+
+fn state_start_tax_sale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    duration: u64,
+) -> Result<Response, ContractError> {
+    let policy = load_tax_policy(deps.storage)?;
+    if info.sender != policy.authority {
+        return Err(ContractError::Unauthorized {});
+    }
+    let property = load_property(deps.storage, house_index)?;
+    ensure_active(&property)?;
+    if TAX_SALES.has(deps.storage, house_index.into()) {
+        return Err(ContractError::TaxSaleInProgress {});
+    }
+    if SALES.has(deps.storage, house_index.into()) {
+        return Err(ContractError::SaleInProgress {});
+    }
+    if !is_tax_delinquent(deps.storage, &env, &property)? {
+        return Err(ContractError::NotDelinquent {});
+    }
+    if duration == 0 {
+        return Err(ContractError::InvalidExpiration {});
+    }
+    let ends_at = checked_plus_seconds(env.block.time, duration)
+        .ok_or(ContractError::InvalidExpiration {})?;
+
+    let account = TAX_ACCOUNTS.load(deps.storage, house_index.into())?;
+    let sale = TaxSale {
+        house_index,
+        minimum_bid: Asset {
+            denom: policy.denom.clone(),
+            amount: account.outstanding(env.block.time),
+        },
+        started_at: env.block.time,
+        ends_at,
+        highest_bid: None,
+    };
+    TAX_SALES.save(deps.storage, house_index.into(), &sale)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "start_tax_sale")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("minimum_bid", sale.minimum_bid.to_string()))
+}
+
+fn state_bid_tax_sale(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    payment: Vec<Asset>,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let mut sale = TAX_SALES
+        .may_load(deps.storage, house_index.into())?
+        .ok_or(ContractError::NoTaxSaleInProgress {})?;
+    if env.block.time >= sale.ends_at {
+        return Err(ContractError::TaxSaleEnded {});
+    }
+    let property = load_property(deps.storage, house_index)?;
+    if sender == property.owner {
+        return Err(ContractError::TransferToSelf {});
+    }
+    let amount = match payment.as_slice() {
+        [asset] if asset.denom == sale.minimum_bid.denom => asset.amount,
+        _ => return Err(ContractError::InvalidTaxPayment {}),
+    };
+    let minimum = match &sale.highest_bid {
+        Some(bid) => bid.amount + Uint128::new(1),
+        None => sale.minimum_bid.amount,
+    };
+    if amount < minimum {
+        return Err(ContractError::BidTooLow { minimum });
+    }
+
+    let mut response = Response::new()
+        .add_attribute("method", "bid_tax_sale")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("bidder", sender.clone())
+        .add_attribute("amount", amount);
+    // refund the bid that was outbid
+    if let Some(bid) = sale.highest_bid.replace(TaxSaleBid {
+        bidder: sender,
+        amount,
+    }) {
+        let refund = Asset {
+            denom: sale.minimum_bid.denom.clone(),
+            amount: bid.amount,
+        };
+        response = response.add_message(send_asset(&bid.bidder, refund)?);
+    }
+    TAX_SALES.save(deps.storage, house_index.into(), &sale)?;
+
+    Ok(response)
+}
+
+/// Hands the title to the highest bidder. The proceeds pay the tax owed to the
+/// authority and the surplus goes to the former owner. If the owner paid the
+/// overdue tax or the title got frozen meanwhile, the bid is refunded instead.
+fn state_close_tax_sale(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    house_index: u64,
+) -> Result<Response, ContractError> {
+    let sale = TAX_SALES
+        .may_load(deps.storage, house_index.into())?
+        .ok_or(ContractError::NoTaxSaleInProgress {})?;
+    if env.block.time < sale.ends_at {
+        return Err(ContractError::TaxSaleNotEnded {});
+    }
+    TAX_SALES.remove(deps.storage, house_index.into());
+
+    let mut response = Response::new()
+        .add_attribute("method", "close_tax_sale")
+        .add_attribute("house_index", house_index.to_string());
+    let bid = match sale.highest_bid {
+        Some(bid) => bid,
+        None => return Ok(response.add_attribute("outcome", "no_bids")),
+    };
+    let denom = sale.minimum_bid.denom;
+    let mut property = load_property(deps.storage, house_index)?;
+    if property.status != PropertyStatus::Active
+        || !is_tax_delinquent(deps.storage, &env, &property)?
+    {
+        let refund = Asset {
+            denom,
+            amount: bid.amount,
+        };
+        return Ok(response
+            .add_message(send_asset(&bid.bidder, refund)?)
+            .add_attribute("outcome", "redeemed"));
+    }
+
+    let policy = load_tax_policy(deps.storage)?;
+    let mut account = TAX_ACCOUNTS.load(deps.storage, house_index.into())?;
+    account.accrue(env.block.time);
+    let debt = account.arrears.min(bid.amount);
+    account.arrears -= debt;
+    account.last_payment = Some(env.block.time);
+    TAX_ACCOUNTS.save(deps.storage, house_index.into(), &account)?;
+
+    let former_owner = property.owner.clone();
+    let refunds = transfer_title(
+        deps.storage,
+        &env,
+        &mut property,
+        bid.bidder,
+        Some(format!("tax sale for {}", bid.amount)),
+    )?;
+
+    response = response.add_message(send_asset(
+        &policy.authority,
+        Asset {
+            denom: denom.clone(),
+            amount: debt,
+        },
+    )?);
+    let surplus = bid.amount - debt;
+    if !surplus.is_zero() {
+        response = response.add_message(send_asset(
+            &former_owner,
+            Asset {
+                denom,
+                amount: surplus,
+            },
+        )?);
+    }
+    Ok(response
+        .add_messages(refunds)
+        .add_attribute("outcome", "sold")
+        .add_attribute("new_owner", property.owner)
+        .add_attribute("surplus", surplus))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn state_create_lease(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    tenant: String,
+    rent: Asset,
+    period: u64,
+    start: Timestamp,
+    end: Timestamp,
+    deposit: Uint128,
+) -> Result<Response, ContractError> {
+    let property = load_property(deps.storage, house_index)?;
+    if info.sender != property.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_active(&property)?;
+    let config = CONFIG.load(deps.storage)?;
+    let rent = validate_asset(deps.api, &config, rent)?;
+    let first_period_end = checked_plus_seconds(start, period);
+    if period == 0 || first_period_end.map_or(true, |first| end < first) || end <= env.block.time {
+        return Err(ContractError::InvalidLeaseTerm {});
+    }
+    let tenant = deps.api.addr_validate(&tenant)?;
+    if tenant == property.owner {
+        return Err(ContractError::TransferToSelf {});
+    }
+
+    let lease_id = leases()
+        .prefix(house_index.into())
+        .range(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(1, |(_, lease)| lease.lease_id + 1);
+    let lease = Lease {
+        lease_id,
+        house_index,
+        landlord: property.owner,
+        tenant,
+        rent,
+        period,
+        start,
+        end,
+        deposit,
+        status: LeaseStatus::Proposed,
+        created_at: env.block.time,
+        accepted_at: None,
+        paid_through: start,
+        claims: vec![],
+        deposit_released_at: None,
+    };
+    leases().save(deps.storage, (house_index.into(), lease_id.into()), &lease)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "create_lease")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("lease_id", lease_id.to_string())
+        .add_attribute("tenant", lease.tenant))
+}
+
+fn state_accept_lease(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    payment: Vec<Asset>,
+    house_index: u64,
+    lease_id: u64,
+) -> Result<Response, ContractError> {
+    let mut lease = load_lease(deps.storage, house_index, lease_id)?;
+    if sender != lease.tenant {
+        return Err(ContractError::Unauthorized {});
+    }
+    if lease.status != LeaseStatus::Proposed {
+        return Err(ContractError::LeaseNotProposed {});
+    }
+    if env.block.time >= lease.end {
+        return Err(ContractError::InvalidLeaseTerm {});
+    }
+    // the deposit stays in escrow until the lease is over
+    if !lease.deposit.is_zero() {
+        let deposit = Asset {
+            denom: lease.rent.denom.clone(),
+            amount: lease.deposit,
+        };
+        must_pay_exactly(&payment, &deposit)?;
+    } else if !payment.is_empty() {
+        return Err(ContractError::UnexpectedPayment {});
+    }
+
+    lease.status = LeaseStatus::Active;
+    lease.accepted_at = Some(env.block.time);
+    leases().save(deps.storage, (house_index.into(), lease_id.into()), &lease)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "accept_lease")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("lease_id", lease_id.to_string()))
+}
+
+fn state_pay_rent(
+    deps: DepsMut,
+    _env: Env,
+    sender: Addr,
+    payment: Vec<Asset>,
+    house_index: u64,
+    lease_id: u64,
+) -> Result<Response, ContractError> {
+    let mut lease = load_lease(deps.storage, house_index, lease_id)?;
+    if sender != lease.tenant {
+        return Err(ContractError::Unauthorized {});
+    }
+    if lease.status != LeaseStatus::Active {
+        return Err(ContractError::LeaseNotActive {});
+    }
+    if lease.paid_through >= lease.end {
+        return Err(ContractError::LeaseFullyPaid {});
+    }
+    let paid = match payment.as_slice() {
+        [asset]
+            if asset.denom == lease.rent.denom
+                && !asset.amount.is_zero()
+                && asset.amount.u128() % lease.rent.amount.u128() == 0 =>
+        {
+            asset.clone()
+        }
+        _ => {
+            return Err(ContractError::InvalidRentPayment {
+                rent: lease.rent.clone(),
+            })
+        }
+    };
+
+    // a last, shorter period is paid in full but no rent beyond it
+    let remaining = lease.end.seconds() - lease.paid_through.seconds();
+    let periods_left = (remaining + lease.period - 1) / lease.period;
+    let periods = (paid.amount / lease.rent.amount).u128();
+    if periods > periods_left as u128 {
+        return Err(ContractError::RentExceedsLease {
+            periods: periods_left,
+        });
+    }
+    lease.paid_through = checked_plus_seconds(lease.paid_through, periods as u64 * lease.period)
+        .map_or(lease.end, |paid_through| paid_through.min(lease.end));
+    leases().save(deps.storage, (house_index.into(), lease_id.into()), &lease)?;
+
+    // rent follows the title, it goes to whoever owns the property now
+    let property = load_property(deps.storage, house_index)?;
+    Ok(Response::new()
+        .add_message(send_asset(&property.owner, paid.clone())?)
+        .add_attribute("method", "pay_rent")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("lease_id", lease_id.to_string())
+        .add_attribute("paid", paid.to_string())
+        .add_attribute("paid_through", lease.paid_through.to_string()))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn state_claim_deposit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    lease_id: u64,
+    amount: Uint128,
+    reason: String,
+) -> Result<Response, ContractError> {
+    let mut lease = load_lease(deps.storage, house_index, lease_id)?;
+    // claims go to whoever owns the property now, like the rent
+    let property = load_property(deps.storage, house_index)?;
+    if info.sender != property.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !lease.holds_deposit() {
+        return Err(ContractError::DepositNotHeld {});
+    }
+    if env.block.time < lease.end {
+        return Err(ContractError::LeaseNotEnded {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let window_closes = checked_plus_seconds(lease.end, config.deposit_dispute_window);
+    if window_closes.map_or(false, |closes| env.block.time >= closes) {
+        return Err(ContractError::ClaimWindowClosed {});
+    }
+    if amount.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+    let available = lease.deposit - lease.claimed();
+    if amount > available {
+        return Err(ContractError::ClaimExceedsDeposit { available });
+    }
+
+    let claim_id = lease.claims.len() as u64 + 1;
+    lease.claims.push(DepositClaim {
+        claim_id,
+        amount,
+        reason,
+        filed_at: env.block.time,
+        status: ClaimStatus::Pending,
+    });
+    leases().save(deps.storage, (house_index.into(), lease_id.into()), &lease)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "claim_deposit")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("lease_id", lease_id.to_string())
+        .add_attribute("claim_id", claim_id.to_string())
+        .add_attribute("amount", amount))
+}
+
+fn state_dispute_deposit_claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    lease_id: u64,
+    claim_id: u64,
+) -> Result<Response, ContractError> {
+    let mut lease = load_lease(deps.storage, house_index, lease_id)?;
+    if info.sender != lease.tenant {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !lease.holds_deposit() {
+        return Err(ContractError::DepositNotHeld {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let claim = load_deposit_claim(&mut lease, claim_id)?;
+    if claim.status != ClaimStatus::Pending {
+        return Err(ContractError::ClaimNotPending {});
+    }
+    let window_closes = checked_plus_seconds(claim.filed_at, config.deposit_dispute_window);
+    if window_closes.map_or(false, |closes| env.block.time >= closes) {
+        return Err(ContractError::DisputeWindowClosed {});
+    }
+    claim.status = ClaimStatus::Disputed;
+    leases().save(deps.storage, (house_index.into(), lease_id.into()), &lease)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "dispute_deposit_claim")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("lease_id", lease_id.to_string())
+        .add_attribute("claim_id", claim_id.to_string()))
+}
+
+fn state_resolve_deposit_claim(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    house_index: u64,
+    lease_id: u64,
+    claim_id: u64,
+    uphold: bool,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if !config.is_arbitrator(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let mut lease = load_lease(deps.storage, house_index, lease_id)?;
+    let claim = load_deposit_claim(&mut lease, claim_id)?;
+    if claim.status != ClaimStatus::Disputed {
+        return Err(ContractError::ClaimNotDisputed {});
+    }
+    claim.status = if uphold {
+        ClaimStatus::Upheld
+    } else {
+        ClaimStatus::Rejected
+    };
+    leases().save(deps.storage, (house_index.into(), lease_id.into()), &lease)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "resolve_deposit_claim")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("lease_id", lease_id.to_string())
+        .add_attribute("claim_id", claim_id.to_string())
+        .add_attribute("upheld", uphold.to_string()))
+}
+
+fn state_release_deposit(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    house_index: u64,
+    lease_id: u64,
+) -> Result<Response, ContractError> {
+    let mut lease = load_lease(deps.storage, house_index, lease_id)?;
+    if !lease.holds_deposit() {
+        return Err(ContractError::DepositNotHeld {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let window = config.deposit_dispute_window;
+    // no more claims can be filed and every claim is past dispute or decided
+    let passed = |time: Timestamp| {
+        checked_plus_seconds(time, window).map_or(false, |closes| env.block.time >= closes)
+    };
+    let settled = passed(lease.end)
+        && lease.claims.iter().all(|claim| match claim.status {
+            ClaimStatus::Pending => passed(claim.filed_at),
+            ClaimStatus::Disputed => false,
+            ClaimStatus::Upheld | ClaimStatus::Rejected => true,
+        });
+    if !settled {
+        return Err(ContractError::DepositNotReleasable {});
+    }
+
+    let deducted = lease.claimed();
+    let remainder = lease.deposit - deducted;
+    lease.deposit_released_at = Some(env.block.time);
+    leases().save(deps.storage, (house_index.into(), lease_id.into()), &lease)?;
+
+    let property = load_property(deps.storage, house_index)?;
+    let mut response = Response::new()
+        .add_attribute("method", "release_deposit")
+        .add_attribute("house_index", house_index.to_string())
+        .add_attribute("lease_id", lease_id.to_string())
+        .add_attribute("deducted", deducted)
+        .add_attribute("refunded", remainder);
+    for &(recipient, amount) in [(&property.owner, deducted), (&lease.tenant, remainder)].iter() {
+        if !amount.is_zero() {
+            let asset = Asset {
+                denom: lease.rent.denom.clone(),
+                amount,
+            };
+            response = response.add_message(send_asset(recipient, asset)?);
+        }
+    }
+    Ok(response)
+}
+
+fn load_deposit_claim(
+    lease: &mut Lease,
+    claim_id: u64,
+) -> Result<&mut DepositClaim, ContractError> {
+    lease
+        .claims
+        .iter_mut()
+        .find(|claim| claim.claim_id == claim_id)
+        .ok_or(ContractError::DepositClaimDoesNotExist {})
+}
+
+fn load_lease(
+    storage: &dyn Storage,
+    house_index: u64,
+    lease_id: u64,
+) -> Result<Lease, ContractError> {
+    leases()
+        .may_load(storage, (house_index.into(), lease_id.into()))?
+        .ok_or(ContractError::LeaseDoesNotExist {})
+}
+
+fn validate_document_hash(document_hash: &str) -> Result<(), ContractError> {
+    if document_hash.len() != 64 || !document_hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ContractError::InvalidDocumentHash {});
+    }
+    Ok(())
+}
+
+/// Checks a price or fee: a non-zero amount of a native coin or an accepted CW20.
+fn validate_asset(api: &dyn Api, config: &Config, asset: Asset) -> Result<Asset, ContractError> {
+    if asset.amount.is_zero() {
+        return Err(ContractError::InvalidPrice {});
+    }
+    Ok(Asset {
+        denom: validate_denom(api, config, asset.denom)?,
+        amount: asset.amount,
+    })
+}
+
+fn validate_denom(api: &dyn Api, config: &Config, denom: Denom) -> Result<Denom, ContractError> {
+    match denom {
+        Denom::Native(denom) => Ok(Denom::Native(denom)),
+        Denom::Cw20(token) => {
+            let token = api.addr_validate(token.as_str())?;
+            if !config.accepts_token(&token) {
+                return Err(ContractError::TokenNotAccepted {});
+            }
+            Ok(Denom::Cw20(token))
+        }
+    }
+}
+
+/// Pays out an asset held by the contract.
+fn send_asset(recipient: &Addr, asset: Asset) -> StdResult<CosmosMsg> {
+    let msg = match asset.denom {
+        Denom::Native(denom) => BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin {
+                denom,
+                amount: asset.amount,
+            }],
+        }
+        .into(),
+        Denom::Cw20(token) => WasmMsg::Execute {
+            contract_addr: token.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount: asset.amount,
+            })?,
+            funds: vec![],
+        }
+        .into(),
+    };
+    Ok(msg)
+}
+
+/// Native funds sent along with the message.
+fn native_payment(info: &MessageInfo) -> Vec<Asset> {
+    info.funds.iter().cloned().map(Asset::from).collect()
+}
+
+fn must_pay_exactly(payment: &[Asset], expected: &Asset) -> Result<(), ContractError> {
+    match payment {
+        [asset] if asset == expected => Ok(()),
+        _ => Err(ContractError::IncorrectPayment {
+            expected: expected.clone(),
+        }),
+    }
+}
+
+/// `time` moved forward by `seconds`, or `None` past the largest timestamp.
+fn checked_plus_seconds(time: Timestamp, seconds: u64) -> Option<Timestamp> {
+    seconds
+        .checked_mul(1_000_000_000)
+        .and_then(|nanos| time.nanos().checked_add(nanos))
+        .map(Timestamp::from_nanos)
+}
+
+fn ensure_active(property: &Property) -> Result<(), ContractError> {
+    if property.status != PropertyStatus::Active {
+        return Err(ContractError::PropertyNotActive {
+            status: property.status,
+        });
+    }
+    Ok(())
+}
+
+/// Moves the property to `status`, minting or burning its title token as the
+/// status requires.
+fn change_status(
+    storage: &mut dyn Storage,
+    property: &mut Property,
+    status: PropertyStatus,
+) -> Result<(), ContractError> {
+    if !property.status.can_transition_to(status) {
+        return Err(ContractError::InvalidStatusTransition {
+            from: property.status,
+            to: status,
+        });
+    }
+    if property.status.is_token() != status.is_token() {
+        STATE.update(storage, |mut state| -> StdResult<_> {
+            if status.is_token() {
+                state.num_tokens += 1;
+            } else {
+                state.num_tokens -= 1;
+            }
+            Ok(state)
+        })?;
+    }
+    property.status = status;
+    Ok(())
+}
+
+/// Checks that nothing on record prevents the title from changing hands.
+fn ensure_transferable(
+    storage: &dyn Storage,
+    env: &Env,
+    property: &Property,
+) -> Result<(), ContractError> {
+    ensure_active(property)?;
+    // a subdivision or merger awaiting approval has already claimed the title
+    if !property.children.is_empty() {
+        return Err(ContractError::RestructuringPending {});
+    }
+    if TAX_SALES.has(storage, property.house_index.into()) {
+        return Err(ContractError::TaxSaleInProgress {});
+    }
+    if is_tax_delinquent(storage, env, property)? {
+        return Err(ContractError::PropertyTaxDelinquent {});
+    }
+    if SALES.has(storage, property.house_index.into()) {
+        return Err(ContractError::SaleInProgress {});
+    }
+    if let Some(lien) = load_liens(storage, property.house_index)?
+        .into_iter()
+        .find(|lien| lien.is_active() && !lien.transfer_consent)
+    {
+        return Err(ContractError::LienOutstanding {
+            lien_id: lien.lien_id,
+        });
+    }
+    Ok(())
+}
+
+/// Lienholder consent covers a change of owner, not the parcel itself going
+/// away, so retiring a record needs every lien released first.
+fn ensure_no_active_liens(storage: &dyn Storage, house_index: u64) -> Result<(), ContractError> {
+    if let Some(lien) = load_liens(storage, house_index)?
+        .into_iter()
+        .find(|lien| lien.is_active())
+    {
+        return Err(ContractError::LienOutstanding {
+            lien_id: lien.lien_id,
+        });
+    }
+    Ok(())
+}
+
+/// Records the unreleased encumbrances of a retired parcel on its successor,
+/// leaving out those held for one of the `merged` parcels.
+fn carry_encumbrances(
+    storage: &mut dyn Storage,
+    env: &Env,
+    from: u64,
+    to: u64,
+    merged: &[u64],
+) -> StdResult<()> {
+    let encumbrances = ENCUMBRANCES
+        .prefix(from.into())
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, encumbrance)| encumbrance))
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut encumbrance_id = ENCUMBRANCES
+        .prefix(to.into())
+        .range(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(1, |(_, encumbrance)| encumbrance.encumbrance_id + 1);
+    for encumbrance in encumbrances {
+        if encumbrance.released_at.is_some() {
+            continue;
+        }
+        // an easement ends once the land it serves is part of the same parcel
+        if let Beneficiary::Parcel { house_index } = &encumbrance.beneficiary {
+            if merged.contains(house_index) {
+                continue;
+            }
+        }
+        let carried = Encumbrance {
+            encumbrance_id,
+            house_index: to,
+            recorded_at: env.block.time,
+            ..encumbrance
+        };
+        ENCUMBRANCES.save(storage, (to.into(), encumbrance_id.into()), &carried)?;
+        encumbrance_id += 1;
+    }
+    Ok(())
+}
+
+fn load_property(storage: &dyn Storage, house_index: u64) -> Result<Property, ContractError> {
+    properties()
+        .may_load(storage, house_index.into())?
+        .ok_or(ContractError::RealEstateDoesNotExist {})
+}
+
+/// Moves the title to `new_owner` and records the change in the chain of title.
+/// Any transfer still on offer for the property, its marketplace listing,
+/// minimum offer and any CW721 approvals are dropped. Lienholder consents are
+/// used up, but the liens themselves stay attached to the property. Offers
+/// still open are lost, the returned messages refund their escrow.
+fn transfer_title(
+    storage: &mut dyn Storage,
+    env: &Env,
+    property: &mut Property,
+    new_owner: Addr,
+    memo: Option<String>,
+) -> StdResult<Vec<CosmosMsg>> {
+    let previous_owner = std::mem::replace(&mut property.owner, new_owner.clone());
+    properties().save(storage, property.house_index.into(), property)?;
+    PENDING_TRANSFERS.remove(storage, property.house_index.into());
+    NFT_APPROVALS.remove(storage, property.house_index.into());
+    LISTINGS.remove(storage, property.house_index.into());
+    MINIMUM_OFFERS.remove(storage, property.house_index.into());
+    for mut lien in load_liens(storage, property.house_index)? {
+        if lien.transfer_consent {
+            lien.transfer_consent = false;
+            LIENS.save(
+                storage,
+                (property.house_index.into(), lien.lien_id.into()),
+                &lien,
+            )?;
+        }
+    }
+    append_title_history(
+        storage,
+        env,
+        property.house_index,
+        Some(previous_owner),
+        new_owner,
+        memo,
+    )?;
+    refund_open_offers(storage, property.house_index)
+}
+
+/// Marks every open offer on the property lost and returns the refunds.
+fn refund_open_offers(storage: &mut dyn Storage, house_index: u64) -> StdResult<Vec<CosmosMsg>> {
+    let open = OFFERS
+        .prefix(house_index.into())
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, offer)| offer))
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |offer| offer.status == OfferStatus::Open)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut refunds = vec![];
+    for mut offer in open {
+        offer.status = OfferStatus::Lost;
+        OFFERS.save(storage, (house_index.into(), offer.offer_id.into()), &offer)?;
+        refunds.push(send_asset(&offer.buyer, offer.amount)?);
+    }
+    Ok(refunds)
+}
+
+fn append_title_history(
+    storage: &mut dyn Storage,
+    env: &Env,
+    house_index: u64,
+    previous_owner: Option<Addr>,
+    new_owner: Addr,
+    memo: Option<String>,
+) -> StdResult<()> {
+    let last = TITLE_HISTORY
+        .prefix(house_index.into())
+        .range(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+    let sequence = last.map_or(1, |(_, transfer)| transfer.sequence + 1);
+
+    let transfer = TitleTransfer {
+        sequence,
+        previous_owner,
+        new_owner,
+        block_height: env.block.height,
+        time: env.block.time,
+        memo,
+    };
+    TITLE_HISTORY.save(storage, (house_index.into(), sequence.into()), &transfer)
+}
+
+fn query_config(deps: Deps, _env: Env) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse { config })
+}
+
+fn load_property_by_house_name(deps: Deps, house_name: String) -> StdResult<Property> {
+    properties()
+        .idx
+        .house_address
+        .item(deps.storage, house_name)?
+        .map(|(_, property)| property)
+        .ok_or_else(|| StdError::generic_err("House Not Found in Blockchain"))
+}
+
+fn load_property_by_house_index(deps: Deps, house_index: u64) -> StdResult<Property> {
+    properties()
+        .may_load(deps.storage, house_index.into())?
+        .ok_or_else(|| StdError::generic_err("House Not Found in Blockchain"))
+}
+
+fn query_owner_by_house_name(deps: Deps, _env: Env, house_name: String) -> StdResult<Addr> {
+    Ok(load_property_by_house_name(deps, house_name)?.owner)
+}
+
+fn query_owner_by_house_index(deps: Deps, _env: Env, house_index: u64) -> StdResult<Addr> {
+    Ok(load_property_by_house_index(deps, house_index)?.owner)
+}
+
+fn property_response(deps: Deps, property: Property) -> StdResult<PropertyResponse> {
+    let encumbrances = ENCUMBRANCES
+        .prefix(property.house_index.into())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, encumbrance)| encumbrance))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PropertyResponse {
+        property,
+        encumbrances,
+    })
+}
+
+fn query_property_by_house_name(
+    deps: Deps,
+    _env: Env,
+    house_name: String,
+) -> StdResult<PropertyResponse> {
+    let property = load_property_by_house_name(deps, house_name)?;
+    property_response(deps, property)
+}
+
+fn query_property_by_house_index(
+    deps: Deps,
+    _env: Env,
+    house_index: u64,
+) -> StdResult<PropertyResponse> {
+    let property = load_property_by_house_index(deps, house_index)?;
+    property_response(deps, property)
+}
+
+fn add_validator(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    validator_addr: Addr,
+    vault_denom: String,
+) -> Result<Response, ContractError> {
+    // check if the validator exists in the blockchain
+    if deps
+        .querier
+        .query_validator(validator_addr.clone())?
+        .is_none()
+    {
+        return Err(ContractError::ValidatorDoesNotExist {});
+    }
+
+    let amount_to_stake_per_validator = Uint128::new(10);
+
+    let funds = info.funds.first();
+    if funds.is_none() {
+        return Err(ContractError::InsufficientFunds {});
+    }
+
+    if funds.unwrap().amount.lt(&amount_to_stake_per_validator) {
+        return Err(ContractError::InsufficientFunds {});
+    }
+
+    let msg = StakingMsg::Delegate {
+        validator: validator_addr.to_string(),
+        amount: Coin {
+            denom: vault_denom.clone(),
+            amount: amount_to_stake_per_validator,
+        },
+    };
+
+    Ok(Response::new()
+        .add_messages([msg])
+        .add_attribute("method", "add_validator"))
+}
+
+fn state_staking_delegate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    validator_addr: Addr,
+    vault_denom: String,
+    amount_to_delegate: u64,
+) -> Result<Response, ContractError> {
+    let msg = StakingMsg::Delegate {
+        validator: validator_addr.to_string(),
+        amount: Coin {
+            denom: vault_denom.clone(),
+            amount: Uint128::new(amount_to_delegate.into()),
+        },
+    };
+    Ok(Response::new().add_messages([msg]))
+}
+
+fn state_staking_undelegate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    validator_addr: Addr,
+    vault_denom: String,
+    amount_to_delegate: u64,
+) -> Result<Response, ContractError> {
+    let msg = StakingMsg::Undelegate {
+        validator: validator_addr.to_string(),
+        amount: Coin {
+            denom: vault_denom.clone(),
+            amount: Uint128::new(amount_to_delegate.into()),
+        },
+    };
+    Ok(Response::new().add_messages([msg]))
+}
+
+fn state_withdraw_rewards(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    validator_addr: Addr,
+) -> Result<Response, ContractError> {
+    // make sure the validator is added first
+    let msg = DistributionMsg::WithdrawDelegatorReward {
+        validator: validator_addr.to_string(),
+    };
+    Ok(Response::new().add_messages([msg]))
+}
+
+fn query_list_properties(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    status: Option<PropertyStatus>,
+) -> StdResult<PropertiesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let range = match status {
+        Some(status) => properties()
+            .idx
+            .status
+            .prefix(status.as_str().as_bytes().to_vec())
+            .range(deps.storage, start, None, Order::Ascending),
+        None => properties().range(deps.storage, start, None, Order::Ascending),
+    };
+    let properties = range
+        .take(limit)
+        .map(|item| item.map(|(_, property)| property))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PropertiesResponse { properties })
+}
+
+fn query_properties_by_owner(
+    deps: Deps,
+    _env: Env,
+    owner: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    status: Option<PropertyStatus>,
+) -> StdResult<PropertiesResponse> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let properties = properties()
+        .idx
+        .owner
+        .prefix(owner.as_bytes().to_vec())
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, property)| property))
+        .filter(|item| match (item, status) {
+            (Ok(property), Some(status)) => property.status == status,
+            _ => true,
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PropertiesResponse { properties })
+}
+
+fn query_title_history(
+    deps: Deps,
+    _env: Env,
+    house_index: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<TitleHistoryResponse> {
+    load_property_by_house_index(deps, house_index)?;
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let history = TITLE_HISTORY
+        .prefix(house_index.into())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, transfer)| transfer))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(TitleHistoryResponse { history })
+}
+
+fn query_pending_transfer(
+    deps: Deps,
+    _env: Env,
+    house_index: u64,
+) -> StdResult<PendingTransferResponse> {
+    let pending_transfer = PENDING_TRANSFERS.may_load(deps.storage, house_index.into())?;
+    Ok(PendingTransferResponse { pending_transfer })
+}
+
+fn query_sale(deps: Deps, _env: Env, house_index: u64) -> StdResult<SaleResponse> {
+    let sale = SALES.may_load(deps.storage, house_index.into())?;
+    Ok(SaleResponse { sale })
+}
+
+fn query_listing(deps: Deps, _env: Env, house_index: u64) -> StdResult<ListingResponse> {
+    let listing = LISTINGS.may_load(deps.storage, house_index.into())?;
+    Ok(ListingResponse { listing })
+}
+
+fn query_listings_for_sale(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    max_price: Option<Asset>,
+    land_use: Option<LandUse>,
+) -> StdResult<ListingsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let mut listings = vec![];
+    for item in LISTINGS.range(deps.storage, start, None, Order::Ascending) {
+        let (_, listing) = item?;
+        if listing.is_expired(env.block.time) {
+            continue;
+        }
+        if let Some(max_price) = &max_price {
+            if listing.price.denom != max_price.denom || listing.price.amount > max_price.amount {
+                continue;
+            }
+        }
+        let property = load_property_by_house_index(deps, listing.house_index)?;
+        if property.status != PropertyStatus::Active
+            || land_use.map_or(false, |land_use| land_use != property.land_use)
+        {
+            continue;
+        }
+        listings.push(listing);
+        if listings.len() == limit {
+            break;
+        }
+    }
+
+    Ok(ListingsResponse { listings })
+}
+
+fn query_offers_by_property(
+    deps: Deps,
+    _env: Env,
+    house_index: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<OffersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let offers = OFFERS
+        .prefix(house_index.into())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, offer)| offer))
+        .collect::<StdResult<Vec<_>>>()?;
+    let minimum = MINIMUM_OFFERS.may_load(deps.storage, house_index.into())?;
+
+    Ok(OffersResponse { minimum, offers })
+}
+
+fn query_list_liens(
+    deps: Deps,
+    _env: Env,
+    house_index: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<LiensResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let liens = LIENS
+        .prefix(house_index.into())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, lien)| lien))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(LiensResponse { liens })
+}
+
+fn query_dispute_history(
+    deps: Deps,
+    _env: Env,
+    house_index: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<DisputesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let disputes = DISPUTES
+        .prefix(house_index.into())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, dispute)| dispute))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(DisputesResponse { disputes })
+}
+
+fn query_registration_request(
+    deps: Deps,
+    _env: Env,
+    house_index: u64,
+) -> StdResult<RegistrationRequestResponse> {
+    let request = REGISTRATION_REQUESTS.may_load(deps.storage, house_index.into())?;
+    Ok(RegistrationRequestResponse { request })
+}
+
+fn query_open_registration_requests(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<RegistrationRequestsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let requests = REGISTRATION_REQUESTS
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, request)| request))
+        .filter(|item| match item {
+            Ok(request) => request.status == RegistrationStatus::Open,
+            Err(_) => true,
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(RegistrationRequestsResponse { requests })
+}
+
+fn query_list_documents(
+    deps: Deps,
+    _env: Env,
+    house_index: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<DocumentsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|sha256| Bound::exclusive(sha256.to_lowercase()));
+    let documents = DOCUMENTS
+        .prefix(house_index.into())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, document)| document))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(DocumentsResponse { documents })
+}
+
+fn query_verify_document(
+    deps: Deps,
+    _env: Env,
+    house_index: u64,
+    sha256: String,
+) -> StdResult<VerifyDocumentResponse> {
+    let document = DOCUMENTS.may_load(deps.storage, (house_index.into(), sha256.to_lowercase()))?;
+    Ok(VerifyDocumentResponse {
+        anchored: document.is_some(),
+        document,
+    })
+}
+
+fn humanize_approvals(
+    env: &Env,
+    approvals: Vec<NftApproval>,
+    include_expired: bool,
+) -> Vec<Approval> {
+    approvals
+        .into_iter()
+        .filter(|approval| include_expired || !approval.expires.is_expired(&env.block))
+        .map(|approval| Approval {
+            spender: approval.spender.to_string(),
+            expires: approval.expires,
+        })
+        .collect()
+}
+
+fn query_owner_of(
+    deps: Deps,
+    env: Env,
+    token_id: String,
+    include_expired: bool,
+) -> StdResult<OwnerOfResponse> {
+    let property = load_token(deps, &token_id)?;
+    let approvals = NFT_APPROVALS
+        .may_load(deps.storage, property.house_index.into())?
+        .unwrap_or_default();
+    Ok(OwnerOfResponse {
+        owner: property.owner.to_string(),
+        approvals: humanize_approvals(&env, approvals, include_expired),
+    })
+}
+
+fn query_approved_for_all(
+    deps: Deps,
+    env: Env,
+    owner: String,
+    include_expired: bool,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ApprovedForAllResponse> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let operators = NFT_OPERATORS
+        .prefix(&owner)
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, expires)) => include_expired || !expires.is_expired(&env.block),
+            Err(_) => true,
+        })
+        .take(limit)
+        .map(|item| {
+            let (operator, expires) = item?;
+            Ok(Approval {
+                spender: String::from_utf8(operator)?,
+                expires,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ApprovedForAllResponse { operators })
+}
+
+fn query_num_tokens(deps: Deps, _env: Env) -> StdResult<NumTokensResponse> {
+    let count = STATE.load(deps.storage)?.num_tokens;
+    Ok(NumTokensResponse { count })
+}
+
+fn query_contract_info(_deps: Deps, _env: Env) -> StdResult<ContractInfoResponse> {
+    Ok(ContractInfoResponse {
+        name: NFT_NAME.to_string(),
+        symbol: NFT_SYMBOL.to_string(),
+    })
+}
+
+fn query_nft_info(deps: Deps, _env: Env, token_id: String) -> StdResult<NftInfoResponse> {
+    let property = load_token(deps, &token_id)?;
+    Ok(NftInfoResponse {
+        token_uri: None,
+        extension: property,
+    })
+}
+
+fn query_all_nft_info(
+    deps: Deps,
+    env: Env,
+    token_id: String,
+    include_expired: bool,
+) -> StdResult<AllNftInfoResponse> {
+    Ok(AllNftInfoResponse {
+        access: query_owner_of(deps, env.clone(), token_id.clone(), include_expired)?,
+        info: query_nft_info(deps, env, token_id)?,
+    })
+}
+
+fn query_tokens(
+    deps: Deps,
+    _env: Env,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .map(|token_id| parse_token_id(&token_id))
+        .transpose()?
+        .map(Bound::exclusive_int);
+    let tokens = properties()
+        .idx
+        .owner
+        .prefix(owner.as_bytes().to_vec())
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, property)| property))
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |property| property.status.is_token())
+        })
+        .take(limit)
+        .map(|item| item.map(|property| property.house_index.to_string()))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(TokensResponse { tokens })
+}
+
+fn query_all_tokens(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .map(|token_id| parse_token_id(&token_id))
+        .transpose()?
+        .map(Bound::exclusive_int);
+    let tokens = properties()
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, property)| property))
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |property| property.status.is_token())
+        })
+        .take(limit)
+        .map(|item| item.map(|property| property.house_index.to_string()))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(TokensResponse { tokens })
+}
+
+fn query_tax_policy(deps: Deps, _env: Env) -> StdResult<TaxPolicyResponse> {
+    let policy = TAX_POLICY.may_load(deps.storage)?;
+    Ok(TaxPolicyResponse { policy })
+}
+
+fn tax_response(policy: &TaxPolicy, env: &Env, account: TaxAccount) -> TaxResponse {
+    let outstanding = Asset {
+        denom: policy.denom.clone(),
+        amount: account.outstanding(env.block.time),
+    };
+    let overdue = Asset {
+        denom: policy.denom.clone(),
+        amount: account.overdue(env.block.time, policy.grace_period),
+    };
+    TaxResponse {
+        account,
+        outstanding,
+        delinquent: !overdue.amount.is_zero(),
+        overdue,
+    }
+}
+
+fn query_outstanding_tax(deps: Deps, env: Env, house_index: u64) -> StdResult<TaxResponse> {
+    let policy = TAX_POLICY.load(deps.storage)?;
+    load_property_by_house_index(deps, house_index)?;
+    let account = TAX_ACCOUNTS.load(deps.storage, house_index.into())?;
+    Ok(tax_response(&policy, &env, account))
+}
+
+fn query_outstanding_tax_by_owner(
+    deps: Deps,
+    env: Env,
+    owner: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<OwnerTaxResponse> {
+    let policy = TAX_POLICY.load(deps.storage)?;
+    let owner = deps.api.addr_validate(&owner)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let mut taxes = vec![];
+    for item in properties()
+        .idx
+        .owner
+        .prefix(owner.as_bytes().to_vec())
+        .range(deps.storage, start, None, Order::Ascending)
+    {
+        let (_, property) = item?;
+        if let Some(account) = TAX_ACCOUNTS.may_load(deps.storage, property.house_index.into())? {
+            taxes.push(tax_response(&policy, &env, account));
+            if taxes.len() == limit {
+                break;
+            }
+        }
+    }
+    let total = Asset {
+        denom: policy.denom,
+        amount: taxes.iter().map(|tax| tax.outstanding.amount).sum(),
+    };
+
+    Ok(OwnerTaxResponse { taxes, total })
+}
+
+fn query_tax_sale(deps: Deps, _env: Env, house_index: u64) -> StdResult<TaxSaleResponse> {
+    let sale = TAX_SALES.may_load(deps.storage, house_index.into())?;
+    Ok(TaxSaleResponse { sale })
+}
+
+fn query_lease(deps: Deps, _env: Env, house_index: u64, lease_id: u64) -> StdResult<LeaseResponse> {
+    let lease = leases().load(deps.storage, (house_index.into(), lease_id.into()))?;
+    Ok(LeaseResponse { lease })
+}
+
+fn query_active_leases(
+    deps: Deps,
+    env: Env,
+    house_index: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<LeasesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive_int);
+    let leases = leases()
+        .prefix(house_index.into())
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, lease)| lease))
+        .filter(|item| match item {
+            Ok(lease) => lease.is_active(env.block.time),
+            Err(_) => true,
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(LeasesResponse { leases })
+}
+
+fn query_active_leases_by_tenant(
+    deps: Deps,
+    env: Env,
+    tenant: String,
+    start_after: Option<(u64, u64)>,
+    limit: Option<u32>,
+) -> StdResult<LeasesResponse> {
+    let tenant = deps.api.addr_validate(&tenant)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|(house_index, lease_id)| {
+        Bound::exclusive((U64Key::from(house_index), U64Key::from(lease_id)).joined_key())
+    });
+    let leases = leases()
+        .idx
+        .tenant
+        .prefix(tenant.as_bytes().to_vec())
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, lease)| lease))
+        .filter(|item| match item {
+            Ok(lease) => lease.is_active(env.block.time),
+            Err(_) => true,
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(LeasesResponse { leases })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{Coordinates, LandUse};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+    use cosmwasm_std::{coin, coins, from_binary, CosmosMsg, Decimal, MemoryStorage, OwnedDeps};
+
+    type TestDeps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+    fn setup() -> TestDeps {
+        let mut deps = mock_dependencies(&[]);
+        let msg = InstantiateMsg {
+            admin: None,
+            registrars: vec!["registrar".to_string()],
+            arbitrators: vec!["court".to_string()],
+            approval_threshold: None,
+            registration_ttl: None,
+            deposit_dispute_window: None,
+            accepted_tokens: vec![],
+            registration_fee: None,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("admin", &[]), msg).unwrap();
+        deps
+    }
+
+    fn run(
+        deps: &mut TestDeps,
+        env: &Env,
+        sender: &str,
+        funds: &[Coin],
+        msg: BenchmarkExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        execute(deps.as_mut(), env.clone(), mock_info(sender, funds), msg)
+    }
+
+    fn push_msg(house_address: &str, owner: &str) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::PushRealEstateToBlockchain {
+            parcel_id: format!("parcel {}", house_address),
+            house_address: house_address.to_string(),
+            area: 100,
+            land_use: LandUse::Residential,
+            coordinates: Coordinates {
+                latitude: 0,
+                longitude: 0,
+            },
+            owner: owner.to_string(),
+        }
+    }
+
+    fn register(deps: &mut TestDeps, house_address: &str, owner: &str) {
+        let msg = push_msg(house_address, owner);
+        run(deps, &mock_env(), "registrar", &[], msg).unwrap();
+    }
+
+    fn owner_of(deps: &TestDeps, house_index: u64) -> Addr {
+        let msg = BenchmarkQueryMsg::FindOwnerByHouseIndex { house_index };
+        from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+    }
+
+    fn property(deps: &TestDeps, house_index: u64) -> Property {
+        let msg = BenchmarkQueryMsg::FindPropertyByHouseIndex { house_index };
+        let res: PropertyResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.property
+    }
+
+    #[test]
+    fn house_addresses_and_parcels_are_unique() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        register(&mut deps, "2 main st", "alice");
+        let env = mock_env();
+
+        let msg = push_msg("1 main st", "alice");
+        let err = run(&mut deps, &env, "registrar", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::RealEstateAlreadyExists {}));
+        let mut msg = push_msg("3 main st", "alice");
+        if let BenchmarkExecuteMsg::PushRealEstateToBlockchain { parcel_id, .. } = &mut msg {
+            *parcel_id = "parcel 1 main st".to_string();
+        }
+        let err = run(&mut deps, &env, "registrar", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::ParcelAlreadyExists {}));
+
+        let state = STATE.load(&deps.storage).unwrap();
+        assert_eq!(state.num_of_real_state, 2);
+        let (_, house) = properties()
+            .idx
+            .house_address
+            .item(&deps.storage, "2 main st".to_string())
+            .unwrap()
+            .unwrap();
+        assert_eq!(house.house_index, 2);
+    }
+
+    #[test]
+    fn registration_validates_the_parcel() {
+        let mut deps = setup();
+        let env = mock_env();
+
+        let mut msg = push_msg("1 main st", "alice");
+        if let BenchmarkExecuteMsg::PushRealEstateToBlockchain { area, .. } = &mut msg {
+            *area = 0;
+        }
+        let err = run(&mut deps, &env, "registrar", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidArea {}));
+
+        let mut msg = push_msg("1 main st", "alice");
+        if let BenchmarkExecuteMsg::PushRealEstateToBlockchain { coordinates, .. } = &mut msg {
+            coordinates.latitude = -MAX_LATITUDE - 1;
+        }
+        let err = run(&mut deps, &env, "registrar", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidCoordinates {}));
+
+        let mut msg = push_msg("1 main st", "alice");
+        if let BenchmarkExecuteMsg::PushRealEstateToBlockchain { coordinates, .. } = &mut msg {
+            coordinates.longitude = MAX_LONGITUDE + 1;
+        }
+        let err = run(&mut deps, &env, "registrar", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidCoordinates {}));
+
+        register(&mut deps, "1 main st", "alice");
+        let house = property(&deps, 1);
+        assert_eq!(house.parcel_id, "parcel 1 main st");
+        assert_eq!(house.area, 100);
+        assert_eq!(house.land_use, LandUse::Residential);
+        assert_eq!(house.registered_at_height, env.block.height);
+        assert_eq!(house.registered_at, env.block.time);
+    }
+
+    #[test]
+    fn owner_is_found_by_index_and_by_address() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        assert_eq!(owner_of(&deps, 1), "alice");
+        let msg = BenchmarkExecuteMsg::ChangeOwnerofRealEstate {
+            house_address: "1 main st".to_string(),
+            new_owner: "bob".to_string(),
+            memo: None,
+        };
+        run(&mut deps, &mock_env(), "registrar", &[], msg).unwrap();
+        assert_eq!(owner_of(&deps, 1), "bob");
+
+        let msg = BenchmarkQueryMsg::FindOwnerByHouseName {
+            house_name: "1 main st".to_string(),
+        };
+        let owner: Addr = from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(owner, "bob");
+        let msg = BenchmarkQueryMsg::FindPropertyByHouseName {
+            house_name: "1 main st".to_string(),
+        };
+        let res: PropertyResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(res.property, property(&deps, 1));
+
+        let msg = BenchmarkQueryMsg::FindOwnerByHouseIndex { house_index: 2 };
+        assert!(query(deps.as_ref(), mock_env(), msg).is_err());
+        let msg = BenchmarkExecuteMsg::ChangeOwnerofRealEstate {
+            house_address: "2 main st".to_string(),
+            new_owner: "alice".to_string(),
+            memo: None,
+        };
+        let err = run(&mut deps, &mock_env(), "registrar", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::RealEstateDoesNotExist {}));
+    }
+
+    #[test]
+    fn only_registrars_can_correct_the_owner() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let transfer = |new_owner: &str| BenchmarkExecuteMsg::ChangeOwnerofRealEstate {
+            house_address: "1 main st".to_string(),
+            new_owner: new_owner.to_string(),
+            memo: None,
+        };
+
+        let err = run(&mut deps, &env, "mallory", &[], transfer("mallory")).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        // owners go through ProposeTransfer instead
+        let err = run(&mut deps, &env, "alice", &[], transfer("bob")).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        assert!(run(&mut deps, &env, "registrar", &[], transfer("")).is_err());
+        assert_eq!(owner_of(&deps, 1), "alice");
+
+        let res = run(&mut deps, &env, "registrar", &[], transfer("bob")).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "new_owner" && attr.value == "bob"));
+        assert_eq!(owner_of(&deps, 1), "bob");
+
+        let msg = push_msg("2 main st", "");
+        assert!(run(&mut deps, &env, "registrar", &[], msg).is_err());
+    }
+
+    fn title_history(
+        deps: &TestDeps,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Vec<TitleTransfer> {
+        let msg = BenchmarkQueryMsg::TitleHistory {
+            house_index: 1,
+            start_after,
+            limit,
+        };
+        let res: TitleHistoryResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.history
+    }
+
+    #[test]
+    fn every_transfer_extends_the_chain_of_title() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let mut env = mock_env();
+        let transfer =
+            |new_owner: &str, memo: Option<&str>| BenchmarkExecuteMsg::ChangeOwnerofRealEstate {
+                house_address: "1 main st".to_string(),
+                new_owner: new_owner.to_string(),
+                memo: memo.map(str::to_string),
+            };
+        env.block.height += 1;
+        run(
+            &mut deps,
+            &env,
+            "registrar",
+            &[],
+            transfer("bob", Some("sale")),
+        )
+        .unwrap();
+        env.block.height += 1;
+        run(&mut deps, &env, "registrar", &[], transfer("carol", None)).unwrap();
+
+        let history = title_history(&deps, None, None);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].previous_owner, None);
+        assert_eq!(history[0].new_owner, "alice");
+        assert_eq!(history[1].sequence, 2);
+        assert_eq!(history[1].previous_owner, Some(Addr::unchecked("alice")));
+        assert_eq!(history[1].memo, Some("sale".to_string()));
+        assert_eq!(history[2].previous_owner, Some(Addr::unchecked("bob")));
+        assert_eq!(history[2].block_height, env.block.height);
+
+        let page = title_history(&deps, Some(1), Some(1));
+        assert_eq!(page, vec![history[1].clone()]);
+        let msg = BenchmarkQueryMsg::TitleHistory {
+            house_index: 2,
+            start_after: None,
+            limit: None,
+        };
+        assert!(query(deps.as_ref(), mock_env(), msg).is_err());
+    }
+
+    fn config(deps: &TestDeps) -> Config {
+        let msg = BenchmarkQueryMsg::Config {};
+        let res: ConfigResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.config
+    }
+
+    #[test]
+    fn instantiate_defaults_the_admin_and_dedups_registrars() {
+        let mut deps = mock_dependencies(&[]);
+        let msg = InstantiateMsg {
+            admin: None,
+            registrars: vec!["registrar".to_string(), "registrar".to_string()],
+            arbitrators: vec![],
+            approval_threshold: None,
+            registration_ttl: None,
+            deposit_dispute_window: None,
+            accepted_tokens: vec![],
+            registration_fee: None,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        let stored = config(&deps);
+        assert_eq!(stored.admin, "creator");
+        assert_eq!(stored.registrars, vec![Addr::unchecked("registrar")]);
+
+        let mut deps = mock_dependencies(&[]);
+        let msg = InstantiateMsg {
+            admin: Some("admin".to_string()),
+            registrars: vec!["registrar".to_string()],
+            arbitrators: vec![],
+            approval_threshold: None,
+            registration_ttl: None,
+            deposit_dispute_window: None,
+            accepted_tokens: vec![],
+            registration_fee: None,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), msg).unwrap();
+        assert_eq!(config(&deps).admin, "admin");
+    }
+
+    #[test]
+    fn admin_manages_the_registrars() {
+        let mut deps = setup();
+        let env = mock_env();
+        let add = BenchmarkExecuteMsg::AddRegistrar {
+            address: "bob".to_string(),
+        };
+        let remove = BenchmarkExecuteMsg::RemoveRegistrar {
+            address: "bob".to_string(),
+        };
+
+        let err = run(&mut deps, &env, "bob", &[], push_msg("1 main st", "alice")).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = run(&mut deps, &env, "registrar", &[], add.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "admin", &[], add.clone()).unwrap();
+        let err = run(&mut deps, &env, "admin", &[], add).unwrap_err();
+        assert!(matches!(err, ContractError::RegistrarAlreadyExists {}));
+        run(&mut deps, &env, "bob", &[], push_msg("1 main st", "alice")).unwrap();
+
+        run(&mut deps, &env, "admin", &[], remove.clone()).unwrap();
+        let err = run(&mut deps, &env, "admin", &[], remove).unwrap_err();
+        assert!(matches!(err, ContractError::RegistrarDoesNotExist {}));
+        let err = run(&mut deps, &env, "bob", &[], push_msg("2 main st", "alice")).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        assert_eq!(config(&deps).registrars, vec![Addr::unchecked("registrar")]);
+    }
+
+    #[test]
+    fn admin_can_hand_over_the_role() {
+        let mut deps = setup();
+        let env = mock_env();
+        let transfer = BenchmarkExecuteMsg::TransferAdmin {
+            new_admin: "zed".to_string(),
+        };
+        let err = run(&mut deps, &env, "zed", &[], transfer.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "admin", &[], transfer.clone()).unwrap();
+        assert_eq!(config(&deps).admin, "zed");
+        let err = run(&mut deps, &env, "admin", &[], transfer).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    fn list_properties(deps: &TestDeps, start_after: Option<u64>, limit: Option<u32>) -> Vec<u64> {
+        let msg = BenchmarkQueryMsg::ListProperties {
+            start_after,
+            limit,
+            status: None,
+        };
+        let res: PropertiesResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.properties.iter().map(|p| p.house_index).collect()
+    }
+
+    #[test]
+    fn properties_are_listed_in_pages() {
+        let mut deps = setup();
+        for i in 1..=35 {
+            register(&mut deps, &format!("{} main st", i), "alice");
+        }
+
+        assert_eq!(
+            list_properties(&deps, None, None),
+            (1..=10).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            list_properties(&deps, None, Some(100)).len(),
+            MAX_LIMIT as usize
+        );
+        assert_eq!(
+            list_properties(&deps, Some(30), None),
+            (31..=35).collect::<Vec<_>>()
+        );
+        assert_eq!(list_properties(&deps, Some(3), Some(2)), vec![4, 5]);
+        assert!(list_properties(&deps, Some(35), None).is_empty());
+    }
+
+    fn properties_of(
+        deps: &TestDeps,
+        owner: &str,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Vec<u64> {
+        let msg = BenchmarkQueryMsg::PropertiesByOwner {
+            owner: owner.to_string(),
+            start_after,
+            limit,
+            status: None,
+        };
+        let res: PropertiesResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.properties.iter().map(|p| p.house_index).collect()
+    }
+
+    #[test]
+    fn properties_are_listed_by_owner() {
+        let mut deps = setup();
+        for i in 1..=10 {
+            let owner = if i % 2 == 1 { "alice" } else { "bob" };
+            register(&mut deps, &format!("{} main st", i), owner);
+        }
+
+        assert_eq!(
+            properties_of(&deps, "alice", None, None),
+            vec![1, 3, 5, 7, 9]
+        );
+        assert_eq!(properties_of(&deps, "alice", Some(3), Some(2)), vec![5, 7]);
+        assert!(properties_of(&deps, "carol", None, None).is_empty());
+
+        // the index follows the title
+        let msg = BenchmarkExecuteMsg::ChangeOwnerofRealEstate {
+            house_address: "1 main st".to_string(),
+            new_owner: "bob".to_string(),
+            memo: None,
+        };
+        run(&mut deps, &mock_env(), "registrar", &[], msg).unwrap();
+        assert_eq!(properties_of(&deps, "alice", None, None), vec![3, 5, 7, 9]);
+        assert_eq!(
+            properties_of(&deps, "bob", None, None),
+            vec![1, 2, 4, 6, 8, 10]
+        );
+    }
+
+    fn propose(recipient: &str, expires: Option<Timestamp>) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::ProposeTransfer {
+            house_index: 1,
+            recipient: recipient.to_string(),
+            expires,
+            memo: Some("gift".to_string()),
+        }
+    }
+
+    fn pending_transfer(deps: &TestDeps) -> Option<PendingTransfer> {
+        let msg = BenchmarkQueryMsg::PendingTransfer { house_index: 1 };
+        let res: PendingTransferResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.pending_transfer
+    }
+
+    #[test]
+    fn title_moves_once_the_recipient_accepts() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let accept = BenchmarkExecuteMsg::AcceptTransfer { house_index: 1 };
+
+        let err = run(&mut deps, &env, "bob", &[], accept.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::NoPendingTransfer {}));
+        let err = run(&mut deps, &env, "bob", &[], propose("bob", None)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = run(&mut deps, &env, "alice", &[], propose("alice", None)).unwrap_err();
+        assert!(matches!(err, ContractError::TransferToSelf {}));
+        let past = Some(env.block.time);
+        let err = run(&mut deps, &env, "alice", &[], propose("bob", past)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidExpiration {}));
+
+        run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap();
+        let err = run(&mut deps, &env, "alice", &[], propose("carol", None)).unwrap_err();
+        assert!(matches!(err, ContractError::TransferAlreadyPending {}));
+        assert_eq!(pending_transfer(&deps).unwrap().to, "bob");
+        assert_eq!(owner_of(&deps, 1), "alice");
+
+        let err = run(&mut deps, &env, "carol", &[], accept.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "bob", &[], accept).unwrap();
+        assert_eq!(owner_of(&deps, 1), "bob");
+        assert_eq!(pending_transfer(&deps), None);
+        let history = title_history(&deps, Some(1), None);
+        assert_eq!(history[0].memo, Some("gift".to_string()));
+    }
+
+    #[test]
+    fn pending_transfers_can_be_withdrawn_refused_or_expire() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let mut env = mock_env();
+        let cancel = BenchmarkExecuteMsg::CancelTransfer { house_index: 1 };
+        let reject = BenchmarkExecuteMsg::RejectTransfer { house_index: 1 };
+        let accept = BenchmarkExecuteMsg::AcceptTransfer { house_index: 1 };
+
+        run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap();
+        let err = run(&mut deps, &env, "bob", &[], cancel.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "alice", &[], cancel.clone()).unwrap();
+        let err = run(&mut deps, &env, "alice", &[], cancel).unwrap_err();
+        assert!(matches!(err, ContractError::NoPendingTransfer {}));
+
+        run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap();
+        let err = run(&mut deps, &env, "alice", &[], reject.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "bob", &[], reject).unwrap();
+        assert_eq!(pending_transfer(&deps), None);
+
+        let expires = Some(env.block.time.plus_seconds(10));
+        run(&mut deps, &env, "alice", &[], propose("bob", expires)).unwrap();
+        env.block.time = env.block.time.plus_seconds(10);
+        let err = run(&mut deps, &env, "bob", &[], accept).unwrap_err();
+        assert!(matches!(err, ContractError::TransferExpired {}));
+        assert_eq!(owner_of(&deps, 1), "alice");
+
+        // a registry correction drops whatever was on offer
+        let msg = BenchmarkExecuteMsg::ChangeOwnerofRealEstate {
+            house_address: "1 main st".to_string(),
+            new_owner: "carol".to_string(),
+            memo: None,
+        };
+        run(&mut deps, &env, "registrar", &[], msg).unwrap();
+        assert_eq!(pending_transfer(&deps), None);
+    }
+
+    fn open_sale(deps: &mut TestDeps, price: u128, buyer: Option<&str>) {
+        let msg = BenchmarkExecuteMsg::OpenSale {
+            house_index: 1,
+            price: Uint128::new(price),
+            denom: Denom::Native("uluna".to_string()),
+            buyer: buyer.map(str::to_string),
+        };
+        run(deps, &mock_env(), "alice", &[], msg).unwrap();
+    }
+
+    fn bank_send(recipient: &str, amount: u128, denom: &str) -> CosmosMsg {
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(amount, denom),
+        })
+    }
+
+    #[test]
+    fn sale_requires_the_exact_price() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        open_sale(&mut deps, 100, None);
+        let env = mock_env();
+        let deposit = BenchmarkExecuteMsg::DepositSaleFunds { house_index: 1 };
+
+        let wrong_funds = vec![
+            coins(99, "uluna"),
+            coins(101, "uluna"),
+            coins(100, "uusd"),
+            vec![coin(100, "uluna"), coin(1, "uusd")],
+            vec![],
+        ];
+        for funds in &wrong_funds {
+            let err = run(&mut deps, &env, "bob", funds, deposit.clone()).unwrap_err();
+            match err {
+                ContractError::IncorrectPayment { expected } => {
+                    assert_eq!(expected, Asset::from(coin(100, "uluna")))
+                }
+                err => panic!("unexpected error: {}", err),
+            }
+        }
+
+        let funds = coins(100, "uluna");
+        let err = run(&mut deps, &env, "alice", &funds, deposit.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TransferToSelf {}));
+        run(&mut deps, &env, "bob", &funds, deposit.clone()).unwrap();
+        let err = run(&mut deps, &env, "carol", &funds, deposit).unwrap_err();
+        assert!(matches!(err, ContractError::SaleAlreadyFunded {}));
+
+        let close = BenchmarkExecuteMsg::CloseSale { house_index: 1 };
+        let err = run(&mut deps, &env, "bob", &[], close.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let res = run(&mut deps, &env, "alice", &[], close).unwrap();
+        assert_eq!(res.messages[0].msg, bank_send("alice", 100, "uluna"));
+        assert_eq!(owner_of(&deps, 1), "bob");
+        let history = title_history(&deps, Some(1), None);
+        assert_eq!(history[0].memo, Some("sale for 100uluna".to_string()));
+    }
+
+    #[test]
+    fn reserved_sale_only_accepts_the_named_buyer() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        open_sale(&mut deps, 100, Some("bob"));
+        let env = mock_env();
+        let deposit = BenchmarkExecuteMsg::DepositSaleFunds { house_index: 1 };
+        let close = BenchmarkExecuteMsg::CloseSale { house_index: 1 };
+
+        let err = run(&mut deps, &env, "alice", &[], close.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::SaleNotFunded {}));
+        let funds = coins(100, "uluna");
+        let err = run(&mut deps, &env, "carol", &funds, deposit.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "bob", &funds, deposit).unwrap();
+        run(&mut deps, &env, "alice", &[], close).unwrap();
+        assert_eq!(owner_of(&deps, 1), "bob");
+    }
+
+    #[test]
+    fn open_sale_blocks_other_transfers() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let msg = BenchmarkExecuteMsg::OpenSale {
+            house_index: 1,
+            price: Uint128::zero(),
+            denom: Denom::Native("uluna".to_string()),
+            buyer: None,
+        };
+        let err = run(&mut deps, &env, "alice", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidPrice {}));
+
+        open_sale(&mut deps, 100, None);
+        let err = run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap_err();
+        assert!(matches!(err, ContractError::SaleInProgress {}));
+        let msg = BenchmarkExecuteMsg::OpenSale {
+            house_index: 1,
+            price: Uint128::new(50),
+            denom: Denom::Native("uluna".to_string()),
+            buyer: None,
+        };
+        let err = run(&mut deps, &env, "alice", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::SaleInProgress {}));
+    }
+
+    #[test]
+    fn cancelling_a_funded_sale_refunds_the_buyer() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let cancel = BenchmarkExecuteMsg::CancelSale { house_index: 1 };
+
+        // nothing to refund before the sale is funded
+        open_sale(&mut deps, 100, None);
+        let res = run(&mut deps, &env, "alice", &[], cancel.clone()).unwrap();
+        assert!(res.messages.is_empty());
+
+        open_sale(&mut deps, 100, None);
+        let deposit = BenchmarkExecuteMsg::DepositSaleFunds { house_index: 1 };
+        run(&mut deps, &env, "bob", &coins(100, "uluna"), deposit).unwrap();
+        let err = run(&mut deps, &env, "carol", &[], cancel.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        let res = run(&mut deps, &env, "bob", &[], cancel.clone()).unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].msg, bank_send("bob", 100, "uluna"));
+        assert_eq!(owner_of(&deps, 1), "alice");
+        let err = run(&mut deps, &env, "alice", &[], cancel).unwrap_err();
+        assert!(matches!(err, ContractError::NoSaleInProgress {}));
+    }
+
+    fn register_lien(deps: &mut TestDeps, lienholder: &str, amount: u128) {
+        let msg = BenchmarkExecuteMsg::RegisterLien {
+            house_index: 1,
+            lienholder: lienholder.to_string(),
+            amount: Uint128::new(amount),
+            denom: "uluna".to_string(),
+        };
+        run(deps, &mock_env(), "alice", &[], msg).unwrap();
+    }
+
+    fn liens(deps: &TestDeps) -> Vec<Lien> {
+        let msg = BenchmarkQueryMsg::ListLiens {
+            house_index: 1,
+            start_after: None,
+            limit: None,
+        };
+        let res: LiensResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.liens
+    }
+
+    #[test]
+    fn lien_blocks_transfer_until_released() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let msg = BenchmarkExecuteMsg::RegisterLien {
+            house_index: 1,
+            lienholder: "bank".to_string(),
+            amount: Uint128::new(1000),
+            denom: "uluna".to_string(),
+        };
+        let err = run(&mut deps, &env, "mallory", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        register_lien(&mut deps, "bank", 1000);
+
+        let err = run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap_err();
+        assert!(matches!(err, ContractError::LienOutstanding { lien_id: 1 }));
+        let correction = BenchmarkExecuteMsg::ChangeOwnerofRealEstate {
+            house_address: "1 main st".to_string(),
+            new_owner: "bob".to_string(),
+            memo: None,
+        };
+        let err = run(&mut deps, &env, "registrar", &[], correction).unwrap_err();
+        assert!(matches!(err, ContractError::LienOutstanding { lien_id: 1 }));
+
+        let release = BenchmarkExecuteMsg::ReleaseLien {
+            house_index: 1,
+            lien_id: 1,
+        };
+        let err = run(&mut deps, &env, "alice", &[], release.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "bank", &[], release.clone()).unwrap();
+        let err = run(&mut deps, &env, "bank", &[], release).unwrap_err();
+        assert!(matches!(err, ContractError::LienAlreadyReleased {}));
+        assert_eq!(liens(&deps)[0].released_at, Some(env.block.time));
+
+        run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap();
+    }
+
+    #[test]
+    fn lienholder_consent_is_used_up_by_the_transfer() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        register_lien(&mut deps, "bank", 1000);
+        let env = mock_env();
+        let consent = BenchmarkExecuteMsg::ConsentToTransfer {
+            house_index: 1,
+            lien_id: 1,
+        };
+        let err = run(&mut deps, &env, "alice", &[], consent.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "bank", &[], consent).unwrap();
+
+        run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap();
+        let accept = BenchmarkExecuteMsg::AcceptTransfer { house_index: 1 };
+        run(&mut deps, &env, "bob", &[], accept).unwrap();
+        assert_eq!(owner_of(&deps, 1), "bob");
+
+        // the lien follows the title and the next transfer needs a fresh consent
+        let lien = &liens(&deps)[0];
+        assert!(lien.is_active());
+        assert!(!lien.transfer_consent);
+        let msg = BenchmarkExecuteMsg::ProposeTransfer {
+            house_index: 1,
+            recipient: "carol".to_string(),
+            expires: None,
+            memo: None,
+        };
+        let err = run(&mut deps, &env, "bob", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::LienOutstanding { lien_id: 1 }));
+    }
+
+    #[test]
+    fn junior_liens_move_up_when_a_senior_lien_is_released() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        register_lien(&mut deps, "first", 100);
+        register_lien(&mut deps, "second", 200);
+        register_lien(&mut deps, "third", 300);
+        let env = mock_env();
+        let priorities = |deps: &TestDeps| -> Vec<u32> {
+            liens(deps).iter().map(|lien| lien.priority).collect()
+        };
+        assert_eq!(priorities(&deps), vec![1, 2, 3]);
+
+        let release = |lien_id| BenchmarkExecuteMsg::ReleaseLien {
+            house_index: 1,
+            lien_id,
+        };
+        run(&mut deps, &env, "first", &[], release(1)).unwrap();
+        assert_eq!(priorities(&deps), vec![1, 1, 2]);
+        // a registrar may also record a release
+        run(&mut deps, &env, "registrar", &[], release(3)).unwrap();
+        assert_eq!(priorities(&deps), vec![1, 1, 2]);
+        assert!(liens(&deps)[1].is_active());
+
+        // new liens rank behind the ones still outstanding
+        register_lien(&mut deps, "fourth", 400);
+        assert_eq!(liens(&deps)[3].priority, 2);
+        let err = run(&mut deps, &env, "first", &[], release(5)).unwrap_err();
+        assert!(matches!(err, ContractError::LienDoesNotExist {}));
+    }
+
+    const DOCUMENT_HASH: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    fn add_encumbrance(
+        house_index: u64,
+        beneficiary: Beneficiary,
+        document_hash: &str,
+    ) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::AddEncumbrance {
+            house_index,
+            kind: EncumbranceKind::RightOfWay,
+            beneficiary,
+            terms: "access to the road".to_string(),
+            document_hash: document_hash.to_string(),
+        }
+    }
+
+    fn encumbrances(deps: &TestDeps, house_index: u64) -> Vec<Encumbrance> {
+        let msg = BenchmarkQueryMsg::FindPropertyByHouseIndex { house_index };
+        let res: PropertyResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.encumbrances
+    }
+
+    #[test]
+    fn encumbrances_are_recorded_against_the_parcel() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        register(&mut deps, "2 main st", "bob");
+        let env = mock_env();
+        let neighbour = Beneficiary::Parcel { house_index: 2 };
+
+        let msg = add_encumbrance(1, neighbour.clone(), DOCUMENT_HASH);
+        let err = run(&mut deps, &env, "bob", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let msg = add_encumbrance(1, neighbour.clone(), "not a hash");
+        let err = run(&mut deps, &env, "alice", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidDocumentHash {}));
+        let msg = add_encumbrance(1, Beneficiary::Parcel { house_index: 1 }, DOCUMENT_HASH);
+        let err = run(&mut deps, &env, "alice", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidBeneficiary {}));
+        let msg = add_encumbrance(1, Beneficiary::Parcel { house_index: 3 }, DOCUMENT_HASH);
+        let err = run(&mut deps, &env, "alice", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::RealEstateDoesNotExist {}));
+
+        let upper_case = DOCUMENT_HASH.to_uppercase();
+        let msg = add_encumbrance(1, neighbour.clone(), &upper_case);
+        run(&mut deps, &env, "alice", &[], msg).unwrap();
+        let utility = Beneficiary::Address {
+            address: Addr::unchecked("power co"),
+        };
+        let msg = add_encumbrance(1, utility, DOCUMENT_HASH);
+        run(&mut deps, &env, "registrar", &[], msg).unwrap();
+
+        let recorded = encumbrances(&deps, 1);
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[0].encumbrance_id, 1);
+        assert_eq!(recorded[0].beneficiary, neighbour);
+        assert_eq!(recorded[0].document_hash, DOCUMENT_HASH);
+        assert_eq!(recorded[1].encumbrance_id, 2);
+        assert!(encumbrances(&deps, 2).is_empty());
+    }
+
+    #[test]
+    fn only_the_beneficiary_can_release_an_encumbrance() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        register(&mut deps, "2 main st", "bob");
+        let env = mock_env();
+        let msg = add_encumbrance(1, Beneficiary::Parcel { house_index: 2 }, DOCUMENT_HASH);
+        run(&mut deps, &env, "alice", &[], msg).unwrap();
+        let utility = Beneficiary::Address {
+            address: Addr::unchecked("power co"),
+        };
+        run(
+            &mut deps,
+            &env,
+            "alice",
+            &[],
+            add_encumbrance(1, utility, DOCUMENT_HASH),
+        )
+        .unwrap();
+        let release = |encumbrance_id| BenchmarkExecuteMsg::ReleaseEncumbrance {
+            house_index: 1,
+            encumbrance_id,
+        };
+
+        // the burdened owner cannot release it, the dominant parcel's owner can
+        let err = run(&mut deps, &env, "alice", &[], release(1)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "bob", &[], release(1)).unwrap();
+        let err = run(&mut deps, &env, "bob", &[], release(1)).unwrap_err();
+        assert!(matches!(err, ContractError::EncumbranceAlreadyReleased {}));
+
+        let err = run(&mut deps, &env, "bob", &[], release(2)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "power co", &[], release(2)).unwrap();
+        let err = run(&mut deps, &env, "registrar", &[], release(3)).unwrap_err();
+        assert!(matches!(err, ContractError::EncumbranceDoesNotExist {}));
+
+        let recorded = encumbrances(&deps, 1);
+        assert!(recorded
+            .iter()
+            .all(|e| e.released_at == Some(env.block.time)));
+    }
+
+    fn parcel(house_address: &str, area: u64) -> ParcelInfo {
+        ParcelInfo {
+            parcel_id: format!("parcel {}", house_address),
+            house_address: house_address.to_string(),
+            area,
+            land_use: LandUse::Residential,
+            coordinates: Coordinates {
+                latitude: 0,
+                longitude: 0,
+            },
+        }
+    }
+
+    fn approve(
+        deps: &mut TestDeps,
+        env: &Env,
+        house_index: u64,
+        registrar: &str,
+    ) -> Result<Response, ContractError> {
+        let msg = BenchmarkExecuteMsg::ApproveRegistration { house_index };
+        run(deps, env, registrar, &[], msg)
+    }
+
+    fn subdivide(house_index: u64, areas: &[u64]) -> BenchmarkExecuteMsg {
+        let children = areas
+            .iter()
+            .enumerate()
+            .map(|(i, area)| parcel(&format!("{} main st unit {}", house_index, i), *area))
+            .collect();
+        BenchmarkExecuteMsg::SubdivideProperty {
+            house_index,
+            children,
+        }
+    }
+
+    #[test]
+    fn subdivision_must_cover_the_parent_exactly() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+
+        let err = run(&mut deps, &env, "bob", &[], subdivide(1, &[50, 50])).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = run(&mut deps, &env, "alice", &[], subdivide(1, &[100])).unwrap_err();
+        assert!(matches!(err, ContractError::TooFewChildParcels {}));
+        let err = run(&mut deps, &env, "alice", &[], subdivide(1, &[50, 40])).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::AreaMismatch {
+                expected: 100,
+                actual: 90
+            }
+        ));
+
+        // the owner's proposal waits for the registrars, and locks the parent
+        run(&mut deps, &env, "alice", &[], subdivide(1, &[60, 40])).unwrap();
+        let parent = property(&deps, 1);
+        assert_eq!(parent.status, PropertyStatus::Active);
+        assert_eq!(parent.children, vec![2, 3]);
+        assert_eq!(property(&deps, 2).status, PropertyStatus::Pending);
+        let err = run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap_err();
+        assert!(matches!(err, ContractError::RestructuringPending {}));
+        let err = run(&mut deps, &env, "alice", &[], subdivide(1, &[50, 50])).unwrap_err();
+        assert!(matches!(err, ContractError::RestructuringPending {}));
+
+        approve(&mut deps, &env, 2, "registrar").unwrap();
+        assert_eq!(property(&deps, 1).status, PropertyStatus::Retired);
+        for house_index in 2..=3 {
+            let child = property(&deps, house_index);
+            assert_eq!(child.owner, "alice");
+            assert_eq!(child.parents, vec![1]);
+            assert_eq!(child.status, PropertyStatus::Active);
+        }
+        let msg = BenchmarkQueryMsg::TitleHistory {
+            house_index: 2,
+            start_after: None,
+            limit: None,
+        };
+        let res: TitleHistoryResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(res.history[0].memo, Some("subdivided from 1".to_string()));
+
+        // the retired record can no longer change hands or be split again
+        let err = run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::PropertyNotActive {
+                status: PropertyStatus::Retired
+            }
+        ));
+        let err = run(&mut deps, &env, "alice", &[], subdivide(1, &[50, 50])).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::PropertyNotActive {
+                status: PropertyStatus::Retired
+            }
+        ));
+    }
+
+    #[test]
+    fn subdivision_needs_liens_released_and_keeps_encumbrances() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        register(&mut deps, "2 main st", "bob");
+        let env = mock_env();
+        let msg = add_encumbrance(1, Beneficiary::Parcel { house_index: 2 }, DOCUMENT_HASH);
+        run(&mut deps, &env, "alice", &[], msg).unwrap();
+        let utility = Beneficiary::Address {
+            address: Addr::unchecked("power co"),
+        };
+        run(
+            &mut deps,
+            &env,
+            "alice",
+            &[],
+            add_encumbrance(1, utility, DOCUMENT_HASH),
+        )
+        .unwrap();
+        let release = BenchmarkExecuteMsg::ReleaseEncumbrance {
+            house_index: 1,
+            encumbrance_id: 2,
+        };
+        run(&mut deps, &env, "power co", &[], release).unwrap();
+
+        // consenting to a transfer does not let the security disappear
+        register_lien(&mut deps, "bank", 1000);
+        let consent = BenchmarkExecuteMsg::ConsentToTransfer {
+            house_index: 1,
+            lien_id: 1,
+        };
+        run(&mut deps, &env, "bank", &[], consent).unwrap();
+        let err = run(&mut deps, &env, "alice", &[], subdivide(1, &[50, 50])).unwrap_err();
+        assert!(matches!(err, ContractError::LienOutstanding { lien_id: 1 }));
+        let release = BenchmarkExecuteMsg::ReleaseLien {
+            house_index: 1,
+            lien_id: 1,
+        };
+        run(&mut deps, &env, "bank", &[], release).unwrap();
+
+        run(&mut deps, &env, "registrar", &[], subdivide(1, &[50, 50])).unwrap();
+        for house_index in 3..=4 {
+            let carried = encumbrances(&deps, house_index);
+            assert_eq!(carried.len(), 1);
+            assert_eq!(carried[0].house_index, house_index);
+            assert_eq!(
+                carried[0].beneficiary,
+                Beneficiary::Parcel { house_index: 2 }
+            );
+        }
+    }
+
+    fn merge(indices: Vec<u64>, area: u64) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::MergeProperties {
+            indices,
+            merged: parcel("1-2 main st", area),
+        }
+    }
+
+    #[test]
+    fn parcels_with_a_common_owner_merge_into_one_title() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        register(&mut deps, "2 main st", "alice");
+        register(&mut deps, "3 main st", "bob");
+        let env = mock_env();
+
+        let err = run(&mut deps, &env, "alice", &[], merge(vec![1, 1], 200)).unwrap_err();
+        assert!(matches!(err, ContractError::TooFewParcelsToMerge {}));
+        let err = run(&mut deps, &env, "alice", &[], merge(vec![1, 3], 200)).unwrap_err();
+        assert!(matches!(err, ContractError::OwnerMismatch {}));
+        let err = run(&mut deps, &env, "bob", &[], merge(vec![1, 2], 200)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = run(&mut deps, &env, "alice", &[], merge(vec![1, 2], 150)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::AreaMismatch {
+                expected: 200,
+                actual: 150
+            }
+        ));
+
+        register_lien(&mut deps, "bank", 1000);
+        let err = run(&mut deps, &env, "alice", &[], merge(vec![2, 1], 200)).unwrap_err();
+        assert!(matches!(err, ContractError::LienOutstanding { lien_id: 1 }));
+        let release = BenchmarkExecuteMsg::ReleaseLien {
+            house_index: 1,
+            lien_id: 1,
+        };
+        run(&mut deps, &env, "bank", &[], release).unwrap();
+
+        run(&mut deps, &env, "alice", &[], merge(vec![2, 1], 200)).unwrap();
+        assert_eq!(property(&deps, 4).status, PropertyStatus::Pending);
+        assert_eq!(property(&deps, 1).status, PropertyStatus::Active);
+        approve(&mut deps, &env, 4, "registrar").unwrap();
+        let merged = property(&deps, 4);
+        assert_eq!(merged.status, PropertyStatus::Active);
+        assert_eq!(merged.owner, "alice");
+        assert_eq!(merged.parents, vec![1, 2]);
+        for house_index in 1..=2 {
+            let predecessor = property(&deps, house_index);
+            assert_eq!(predecessor.status, PropertyStatus::Retired);
+            assert_eq!(predecessor.children, vec![4]);
+        }
+        let err = run(&mut deps, &env, "alice", &[], merge(vec![1, 4], 400)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::PropertyNotActive {
+                status: PropertyStatus::Retired
+            }
+        ));
+    }
+
+    #[test]
+    fn merge_drops_easements_between_the_merged_parcels() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        register(&mut deps, "2 main st", "alice");
+        register(&mut deps, "3 main st", "bob");
+        let env = mock_env();
+        // 1 serves 2, and 2 serves the neighbouring parcel 3
+        let msg = add_encumbrance(1, Beneficiary::Parcel { house_index: 2 }, DOCUMENT_HASH);
+        run(&mut deps, &env, "alice", &[], msg).unwrap();
+        let msg = add_encumbrance(2, Beneficiary::Parcel { house_index: 3 }, DOCUMENT_HASH);
+        run(&mut deps, &env, "alice", &[], msg).unwrap();
+
+        run(&mut deps, &env, "alice", &[], merge(vec![1, 2], 200)).unwrap();
+        approve(&mut deps, &env, 4, "registrar").unwrap();
+        let carried = encumbrances(&deps, 4);
+        assert_eq!(carried.len(), 1);
+        assert_eq!(
+            carried[0].beneficiary,
+            Beneficiary::Parcel { house_index: 3 }
+        );
+    }
+
+    fn set_status(
+        deps: &mut TestDeps,
+        house_index: u64,
+        status: PropertyStatus,
+    ) -> Result<Response, ContractError> {
+        let msg = BenchmarkExecuteMsg::ChangePropertyStatus {
+            house_index,
+            status,
+        };
+        run(deps, &mock_env(), "registrar", &[], msg)
+    }
+
+    #[test]
+    fn status_changes_follow_the_lifecycle() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        assert_eq!(property(&deps, 1).status, PropertyStatus::Active);
+
+        let msg = BenchmarkExecuteMsg::ChangePropertyStatus {
+            house_index: 1,
+            status: PropertyStatus::Frozen,
+        };
+        let err = run(&mut deps, &mock_env(), "alice", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        // retirement only comes from a subdivision or merger
+        let err = set_status(&mut deps, 1, PropertyStatus::Retired).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidStatusTransition { .. }));
+
+        set_status(&mut deps, 1, PropertyStatus::Frozen).unwrap();
+        let err = run(&mut deps, &mock_env(), "alice", &[], propose("bob", None)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::PropertyNotActive {
+                status: PropertyStatus::Frozen
+            }
+        ));
+        let err = set_status(&mut deps, 1, PropertyStatus::Demolished).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InvalidStatusTransition {
+                from: PropertyStatus::Frozen,
+                to: PropertyStatus::Demolished
+            }
+        ));
+        set_status(&mut deps, 1, PropertyStatus::Active).unwrap();
+        run(&mut deps, &mock_env(), "alice", &[], propose("bob", None)).unwrap();
+
+        // demolition is final
+        register(&mut deps, "2 main st", "alice");
+        set_status(&mut deps, 2, PropertyStatus::Demolished).unwrap();
+        let err = set_status(&mut deps, 2, PropertyStatus::Active).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidStatusTransition { .. }));
+    }
+
+    #[test]
+    fn properties_are_listed_by_status() {
+        let mut deps = setup();
+        for i in 1..=6 {
+            register(&mut deps, &format!("{} main st", i), "alice");
+        }
+        set_status(&mut deps, 2, PropertyStatus::Frozen).unwrap();
+        set_status(&mut deps, 5, PropertyStatus::Frozen).unwrap();
+        set_status(&mut deps, 6, PropertyStatus::Demolished).unwrap();
+
+        let list = |deps: &TestDeps, status, start_after| -> Vec<u64> {
+            let msg = BenchmarkQueryMsg::ListProperties {
+                start_after,
+                limit: None,
+                status: Some(status),
+            };
+            let res: PropertiesResponse =
+                from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+            res.properties.iter().map(|p| p.house_index).collect()
+        };
+        assert_eq!(list(&deps, PropertyStatus::Active, None), vec![1, 3, 4]);
+        assert_eq!(list(&deps, PropertyStatus::Frozen, None), vec![2, 5]);
+        assert_eq!(list(&deps, PropertyStatus::Frozen, Some(2)), vec![5]);
+        assert!(list(&deps, PropertyStatus::Retired, None).is_empty());
+
+        let msg = BenchmarkQueryMsg::PropertiesByOwner {
+            owner: "alice".to_string(),
+            start_after: None,
+            limit: Some(2),
+            status: Some(PropertyStatus::Frozen),
+        };
+        let res: PropertiesResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        let indices: Vec<u64> = res.properties.iter().map(|p| p.house_index).collect();
+        assert_eq!(indices, vec![2, 5]);
+    }
+
+    fn freeze(case_ref: &str) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::FreezeProperty {
+            house_index: 1,
+            reason: "contested inheritance".to_string(),
+            case_ref: case_ref.to_string(),
+        }
+    }
+
+    fn disputes(deps: &TestDeps) -> Vec<Dispute> {
+        let msg = BenchmarkQueryMsg::DisputeHistory {
+            house_index: 1,
+            start_after: None,
+            limit: None,
+        };
+        let res: DisputesResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.disputes
+    }
+
+    #[test]
+    fn arbitrator_freezes_and_releases_a_contested_title() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let confirm = BenchmarkExecuteMsg::ResolveDispute {
+            house_index: 1,
+            outcome: DisputeOutcome::OwnerConfirmed,
+        };
+
+        let err = run(&mut deps, &env, "registrar", &[], freeze("case 1")).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = run(&mut deps, &env, "court", &[], confirm.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::NoOpenDispute {}));
+        let err = set_status(&mut deps, 1, PropertyStatus::UnderDispute).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidStatusTransition { .. }));
+
+        // a frozen title goes back to frozen once the dispute is over
+        set_status(&mut deps, 1, PropertyStatus::Frozen).unwrap();
+        run(&mut deps, &env, "court", &[], freeze("case 1")).unwrap();
+        assert_eq!(property(&deps, 1).status, PropertyStatus::UnderDispute);
+        let err = run(&mut deps, &env, "court", &[], freeze("case 2")).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidStatusTransition { .. }));
+        let err = run(&mut deps, &env, "alice", &[], confirm.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "court", &[], confirm.clone()).unwrap();
+        assert_eq!(property(&deps, 1).status, PropertyStatus::Frozen);
+        assert_eq!(owner_of(&deps, 1), "alice");
+        let err = run(&mut deps, &env, "court", &[], confirm).unwrap_err();
+        assert!(matches!(err, ContractError::NoOpenDispute {}));
+
+        let history = disputes(&deps);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].prior_status, PropertyStatus::Frozen);
+        assert_eq!(history[0].outcome, Some(DisputeOutcome::OwnerConfirmed));
+        assert_eq!(history[0].resolved_by, Some(Addr::unchecked("court")));
+    }
+
+    #[test]
+    fn court_award_moves_the_title_and_refunds_a_funded_sale() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        open_sale(&mut deps, 100, None);
+        let env = mock_env();
+        let deposit = BenchmarkExecuteMsg::DepositSaleFunds { house_index: 1 };
+        run(&mut deps, &env, "bob", &coins(100, "uluna"), deposit).unwrap();
+
+        run(&mut deps, &env, "court", &[], freeze("case 7")).unwrap();
+        // only the court can lift the freeze
+        let err = set_status(&mut deps, 1, PropertyStatus::Active).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidStatusTransition { .. }));
+        let err = run(&mut deps, &env, "alice", &[], propose("dave", None)).unwrap_err();
+        assert!(matches!(err, ContractError::PropertyNotActive { .. }));
+
+        let award = BenchmarkExecuteMsg::ResolveDispute {
+            house_index: 1,
+            outcome: DisputeOutcome::TitleAwarded {
+                new_owner: Addr::unchecked("carol"),
+            },
+        };
+        let res = run(&mut deps, &env, "court", &[], award).unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].msg, bank_send("bob", 100, "uluna"));
+        assert_eq!(owner_of(&deps, 1), "carol");
+        assert_eq!(property(&deps, 1).status, PropertyStatus::Active);
+        let msg = BenchmarkQueryMsg::Sale { house_index: 1 };
+        let res: SaleResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert_eq!(res.sale, None);
+        let history = title_history(&deps, Some(1), None);
+        assert_eq!(history[0].memo, Some("court order case 7".to_string()));
+    }
+
+    #[test]
+    fn admin_manages_the_arbitrators() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let add = BenchmarkExecuteMsg::AddArbitrator {
+            address: "tribunal".to_string(),
+        };
+        let remove = BenchmarkExecuteMsg::RemoveArbitrator {
+            address: "court".to_string(),
+        };
+
+        let err = run(&mut deps, &env, "court", &[], add.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "admin", &[], add.clone()).unwrap();
+        let err = run(&mut deps, &env, "admin", &[], add).unwrap_err();
+        assert!(matches!(err, ContractError::ArbitratorAlreadyExists {}));
+        run(&mut deps, &env, "admin", &[], remove.clone()).unwrap();
+        let err = run(&mut deps, &env, "admin", &[], remove).unwrap_err();
+        assert!(matches!(err, ContractError::ArbitratorDoesNotExist {}));
+
+        let err = run(&mut deps, &env, "court", &[], freeze("case 1")).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "tribunal", &[], freeze("case 1")).unwrap();
+    }
+
+    fn registration_policy(
+        deps: &mut TestDeps,
+        registrars: &[&str],
+        approval_threshold: u32,
+    ) -> Result<Response, ContractError> {
+        let env = mock_env();
+        for registrar in registrars {
+            let msg = BenchmarkExecuteMsg::AddRegistrar {
+                address: registrar.to_string(),
+            };
+            run(deps, &env, "admin", &[], msg)?;
+        }
+        let msg = BenchmarkExecuteMsg::UpdateRegistrationPolicy {
+            approval_threshold,
+            registration_ttl: 100,
+        };
+        run(deps, &env, "admin", &[], msg)
+    }
+
+    fn registration_request(deps: &TestDeps, house_index: u64) -> RegistrationRequest {
+        let msg = BenchmarkQueryMsg::RegistrationRequest { house_index };
+        let res: RegistrationRequestResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.request.unwrap()
+    }
+
+    fn is_registered(deps: &TestDeps, house_index: u64) -> bool {
+        let msg = BenchmarkQueryMsg::FindPropertyByHouseIndex { house_index };
+        query(deps.as_ref(), mock_env(), msg).is_ok()
+    }
+
+    #[test]
+    fn registrations_need_the_approval_threshold() {
+        let mut deps = setup();
+        let err = registration_policy(&mut deps, &["clerk", "notary"], 4).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidThreshold {}));
+        registration_policy(&mut deps, &[], 2).unwrap();
+        let env = mock_env();
+
+        let res = run(
+            &mut deps,
+            &env,
+            "registrar",
+            &[],
+            push_msg("1 main st", "alice"),
+        )
+        .unwrap();
+        assert_eq!(res.attributes[2].value, "open");
+        assert_eq!(property(&deps, 1).status, PropertyStatus::Pending);
+        let err = run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::PropertyNotActive {
+                status: PropertyStatus::Pending
+            }
+        ));
+        let err = approve(&mut deps, &env, 1, "registrar").unwrap_err();
+        assert!(matches!(err, ContractError::AlreadyVoted {}));
+        let err = approve(&mut deps, &env, 1, "alice").unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        let res = approve(&mut deps, &env, 1, "clerk").unwrap();
+        assert_eq!(res.attributes[2].value, "approved");
+        assert_eq!(property(&deps, 1).status, PropertyStatus::Active);
+        assert_eq!(title_history(&deps, None, None).len(), 1);
+        let err = approve(&mut deps, &env, 1, "notary").unwrap_err();
+        assert!(matches!(err, ContractError::NoOpenRegistration {}));
+
+        // the registrars cannot shrink below the threshold
+        let remove = |address: &str| BenchmarkExecuteMsg::RemoveRegistrar {
+            address: address.to_string(),
+        };
+        run(&mut deps, &env, "admin", &[], remove("clerk")).unwrap();
+        let err = run(&mut deps, &env, "admin", &[], remove("notary")).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidThreshold {}));
+    }
+
+    #[test]
+    fn removed_registrars_no_longer_count_toward_the_threshold() {
+        let mut deps = setup();
+        registration_policy(&mut deps, &["clerk", "notary"], 2).unwrap();
+        let env = mock_env();
+        run(
+            &mut deps,
+            &env,
+            "registrar",
+            &[],
+            push_msg("1 main st", "alice"),
+        )
+        .unwrap();
+        let msg = BenchmarkExecuteMsg::RemoveRegistrar {
+            address: "registrar".to_string(),
+        };
+        run(&mut deps, &env, "admin", &[], msg).unwrap();
+
+        approve(&mut deps, &env, 1, "clerk").unwrap();
+        assert_eq!(property(&deps, 1).status, PropertyStatus::Pending);
+        approve(&mut deps, &env, 1, "notary").unwrap();
+        assert_eq!(property(&deps, 1).status, PropertyStatus::Active);
+    }
+
+    #[test]
+    fn registration_is_rejected_once_the_threshold_is_unreachable() {
+        let mut deps = setup();
+        registration_policy(&mut deps, &["clerk", "notary"], 2).unwrap();
+        let env = mock_env();
+        let reject = BenchmarkExecuteMsg::RejectRegistration { house_index: 1 };
+        run(
+            &mut deps,
+            &env,
+            "registrar",
+            &[],
+            push_msg("1 main st", "alice"),
+        )
+        .unwrap();
+
+        let res = run(&mut deps, &env, "clerk", &[], reject.clone()).unwrap();
+        assert_eq!(res.attributes[2].value, "open");
+        assert!(is_registered(&deps, 1));
+        let res = run(&mut deps, &env, "notary", &[], reject).unwrap();
+        assert_eq!(res.attributes[2].value, "rejected");
+        assert!(!is_registered(&deps, 1));
+        assert_eq!(
+            registration_request(&deps, 1).status,
+            RegistrationStatus::Rejected
+        );
+
+        // the address is free to be registered again
+        run(
+            &mut deps,
+            &env,
+            "registrar",
+            &[],
+            push_msg("1 main st", "alice"),
+        )
+        .unwrap();
+        assert_eq!(property(&deps, 2).status, PropertyStatus::Pending);
+    }
+
+    #[test]
+    fn stale_registrations_expire() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        registration_policy(&mut deps, &["clerk"], 2).unwrap();
+        let mut env = mock_env();
+        let expire = BenchmarkExecuteMsg::ExpireRegistration { house_index: 2 };
+
+        run(&mut deps, &env, "alice", &[], subdivide(1, &[50, 50])).unwrap();
+        assert!(registration_request(&deps, 2).approvals.is_empty());
+        let err = run(&mut deps, &env, "anyone", &[], expire.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::RegistrationNotExpired {}));
+
+        env.block.time = env.block.time.plus_seconds(100);
+        let err = approve(&mut deps, &env, 2, "clerk").unwrap_err();
+        assert!(matches!(err, ContractError::RegistrationExpired {}));
+        run(&mut deps, &env, "anyone", &[], expire).unwrap();
+        assert_eq!(
+            registration_request(&deps, 2).status,
+            RegistrationStatus::Expired
+        );
+        assert!(!is_registered(&deps, 2));
+        assert!(!is_registered(&deps, 3));
+
+        // the parent is unlocked again
+        let parent = property(&deps, 1);
+        assert_eq!(parent.status, PropertyStatus::Active);
+        assert!(parent.children.is_empty());
+        run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap();
+    }
+
+    fn anchor(sha256: &str, transfer_sequence: Option<u64>) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::AnchorDocument {
+            house_index: 1,
+            sha256: sha256.to_string(),
+            uri: "ipfs://deed".to_string(),
+            document_type: DocumentType::Deed,
+            transfer_sequence,
+        }
+    }
+
+    #[test]
+    fn documents_are_anchored_once_per_title() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+
+        let err = run(&mut deps, &env, "bob", &[], anchor(DOCUMENT_HASH, None)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = run(&mut deps, &env, "alice", &[], anchor("abc", None)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidDocumentHash {}));
+        let err = run(
+            &mut deps,
+            &env,
+            "alice",
+            &[],
+            anchor(DOCUMENT_HASH, Some(2)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TitleTransferDoesNotExist {}));
+
+        let upper = DOCUMENT_HASH.to_uppercase();
+        run(&mut deps, &env, "alice", &[], anchor(&upper, Some(1))).unwrap();
+        let err = run(
+            &mut deps,
+            &env,
+            "registrar",
+            &[],
+            anchor(DOCUMENT_HASH, None),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DocumentAlreadyAnchored {}));
+
+        let msg = BenchmarkQueryMsg::VerifyDocument {
+            house_index: 1,
+            sha256: upper,
+        };
+        let res: VerifyDocumentResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert!(res.anchored);
+        let document = res.document.unwrap();
+        assert_eq!(document.sha256, DOCUMENT_HASH.to_lowercase());
+        assert_eq!(document.uploader, "alice");
+        assert_eq!(document.transfer_sequence, Some(1));
+
+        let msg = BenchmarkQueryMsg::VerifyDocument {
+            house_index: 2,
+            sha256: DOCUMENT_HASH.to_string(),
+        };
+        let res: VerifyDocumentResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert!(!res.anchored);
+        let msg = BenchmarkQueryMsg::ListDocuments {
+            house_index: 1,
+            start_after: None,
+            limit: None,
+        };
+        let res: DocumentsResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(res.documents, vec![document]);
+    }
+
+    fn tokens(deps: &TestDeps, owner: &str) -> Vec<String> {
+        let msg = BenchmarkQueryMsg::Tokens {
+            owner: owner.to_string(),
+            start_after: None,
+            limit: None,
+        };
+        let res: TokensResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.tokens
+    }
+
+    fn num_tokens(deps: &TestDeps) -> u64 {
+        let msg = BenchmarkQueryMsg::NumTokens {};
+        let res: NumTokensResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.count
+    }
+
+    fn transfer_nft(recipient: &str, token_id: &str) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::TransferNft {
+            recipient: recipient.to_string(),
+            token_id: token_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn approved_spenders_move_title_tokens() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        register(&mut deps, "2 main st", "alice");
+        let env = mock_env();
+        let approve_bob = |expires: Option<Expiration>| BenchmarkExecuteMsg::Approve {
+            spender: "bob".to_string(),
+            token_id: "1".to_string(),
+            expires,
+        };
+
+        let err = run(&mut deps, &env, "carol", &[], approve_bob(None)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let expired = Some(Expiration::AtHeight(env.block.height));
+        let err = run(&mut deps, &env, "alice", &[], approve_bob(expired)).unwrap_err();
+        assert!(matches!(err, ContractError::Expired {}));
+        let err = run(&mut deps, &env, "bob", &[], transfer_nft("bob", "1")).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        run(&mut deps, &env, "alice", &[], approve_bob(None)).unwrap();
+        let msg = BenchmarkQueryMsg::OwnerOf {
+            token_id: "1".to_string(),
+            include_expired: None,
+        };
+        let res: OwnerOfResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg.clone()).unwrap()).unwrap();
+        assert_eq!(res.owner, "alice");
+        assert_eq!(res.approvals.len(), 1);
+        assert_eq!(res.approvals[0].spender, "bob");
+
+        // the approval goes away with the transfer
+        run(&mut deps, &env, "bob", &[], transfer_nft("carol", "1")).unwrap();
+        let res: OwnerOfResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(res.owner, "carol");
+        assert!(res.approvals.is_empty());
+        assert_eq!(title_history(&deps, None, None).len(), 2);
+        let err = run(&mut deps, &env, "bob", &[], transfer_nft("bob", "1")).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let revoke = BenchmarkExecuteMsg::Revoke {
+            spender: "bob".to_string(),
+            token_id: "1".to_string(),
+        };
+        let err = run(&mut deps, &env, "carol", &[], revoke).unwrap_err();
+        assert!(matches!(err, ContractError::ApprovalDoesNotExist {}));
+        assert_eq!(tokens(&deps, "alice"), vec!["2".to_string()]);
+        assert_eq!(tokens(&deps, "carol"), vec!["1".to_string()]);
+
+        // operators move every token of the owner until revoked
+        let msg = BenchmarkExecuteMsg::ApproveAll {
+            operator: "dave".to_string(),
+            expires: None,
+        };
+        run(&mut deps, &env, "alice", &[], msg).unwrap();
+        run(&mut deps, &env, "dave", &[], transfer_nft("dave", "2")).unwrap();
+        assert_eq!(owner_of(&deps, 2), "dave");
+        let revoke_all = BenchmarkExecuteMsg::RevokeAll {
+            operator: "dave".to_string(),
+        };
+        run(&mut deps, &env, "alice", &[], revoke_all.clone()).unwrap();
+        let err = run(&mut deps, &env, "alice", &[], revoke_all).unwrap_err();
+        assert!(matches!(err, ContractError::ApprovalDoesNotExist {}));
+    }
+
+    #[test]
+    fn only_records_in_force_are_title_tokens() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        assert_eq!(num_tokens(&deps), 1);
+
+        run(&mut deps, &env, "alice", &[], subdivide(1, &[50, 50])).unwrap();
+        assert_eq!(num_tokens(&deps), 1);
+        assert_eq!(tokens(&deps, "alice"), vec!["1".to_string()]);
+        let msg = BenchmarkQueryMsg::NftInfo {
+            token_id: "2".to_string(),
+        };
+        assert!(query(deps.as_ref(), mock_env(), msg).is_err());
+
+        approve(&mut deps, &env, 2, "registrar").unwrap();
+        assert_eq!(num_tokens(&deps), 2);
+        assert_eq!(
+            tokens(&deps, "alice"),
+            vec!["2".to_string(), "3".to_string()]
+        );
+        let err = run(&mut deps, &env, "alice", &[], transfer_nft("bob", "1")).unwrap_err();
+        assert!(matches!(err, ContractError::Std(_)));
+
+        set_status(&mut deps, 3, PropertyStatus::Demolished).unwrap();
+        assert_eq!(num_tokens(&deps), 1);
+        let msg = BenchmarkQueryMsg::AllTokens {
+            start_after: None,
+            limit: None,
+        };
+        let res: TokensResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(res.tokens, vec!["2".to_string()]);
+    }
+
+    fn receive(sender: &str, amount: u128, msg: &ReceiveMsg) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: sender.to_string(),
+            amount: Uint128::new(amount),
+            msg: to_binary(msg).unwrap(),
+        })
+    }
+
+    fn cw20_transfer(token: &str, recipient: &str, amount: u128) -> CosmosMsg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: token.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount: Uint128::new(amount),
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    }
+
+    #[test]
+    fn accepted_cw20_tokens_pay_for_sales() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let open = BenchmarkExecuteMsg::OpenSale {
+            house_index: 1,
+            price: Uint128::new(100),
+            denom: Denom::Cw20(Addr::unchecked("token")),
+            buyer: None,
+        };
+        let err = run(&mut deps, &env, "alice", &[], open.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TokenNotAccepted {}));
+
+        let accept = BenchmarkExecuteMsg::AddAcceptedToken {
+            address: "token".to_string(),
+        };
+        let err = run(&mut deps, &env, "alice", &[], accept.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "admin", &[], accept.clone()).unwrap();
+        let err = run(&mut deps, &env, "admin", &[], accept).unwrap_err();
+        assert!(matches!(err, ContractError::TokenAlreadyAccepted {}));
+        run(&mut deps, &env, "alice", &[], open).unwrap();
+
+        let deposit = ReceiveMsg::DepositSaleFunds { house_index: 1 };
+        let err = run(&mut deps, &env, "other", &[], receive("bob", 100, &deposit)).unwrap_err();
+        assert!(matches!(err, ContractError::TokenNotAccepted {}));
+        let err = run(&mut deps, &env, "token", &[], receive("bob", 99, &deposit)).unwrap_err();
+        assert!(matches!(err, ContractError::IncorrectPayment { .. }));
+        let msg = BenchmarkExecuteMsg::DepositSaleFunds { house_index: 1 };
+        let err = run(&mut deps, &env, "bob", &coins(100, "uluna"), msg).unwrap_err();
+        assert!(matches!(err, ContractError::IncorrectPayment { .. }));
+        run(&mut deps, &env, "token", &[], receive("bob", 100, &deposit)).unwrap();
+
+        let close = BenchmarkExecuteMsg::CloseSale { house_index: 1 };
+        let res = run(&mut deps, &env, "alice", &[], close).unwrap();
+        assert_eq!(res.messages[0].msg, cw20_transfer("token", "alice", 100));
+        assert_eq!(owner_of(&deps, 1), "bob");
+    }
+
+    #[test]
+    fn registrars_pay_the_registration_fee() {
+        let mut deps = setup();
+        let env = mock_env();
+        let msg = BenchmarkExecuteMsg::AddAcceptedToken {
+            address: "token".to_string(),
+        };
+        run(&mut deps, &env, "admin", &[], msg).unwrap();
+        let err = run(
+            &mut deps,
+            &env,
+            "registrar",
+            &coins(5, "uluna"),
+            push_msg("1 main st", "alice"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::UnexpectedPayment {}));
+
+        let fee = Asset {
+            denom: Denom::Cw20(Addr::unchecked("token")),
+            amount: Uint128::new(5),
+        };
+        let msg = BenchmarkExecuteMsg::UpdateRegistrationFee {
+            fee: Some(fee.clone()),
+        };
+        run(&mut deps, &env, "admin", &[], msg).unwrap();
+        assert_eq!(config(&deps).registration_fee, Some(fee));
+        let err = run(
+            &mut deps,
+            &env,
+            "registrar",
+            &[],
+            push_msg("1 main st", "alice"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::IncorrectPayment { .. }));
+
+        let push = ReceiveMsg::PushRealEstateToBlockchain {
+            parcel_id: "parcel 1 main st".to_string(),
+            house_address: "1 main st".to_string(),
+            area: 100,
+            land_use: LandUse::Residential,
+            coordinates: Coordinates {
+                latitude: 0,
+                longitude: 0,
+            },
+            owner: "alice".to_string(),
+        };
+        let err = run(&mut deps, &env, "token", &[], receive("alice", 5, &push)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let res = run(
+            &mut deps,
+            &env,
+            "token",
+            &[],
+            receive("registrar", 5, &push),
+        )
+        .unwrap();
+        assert_eq!(res.messages[0].msg, cw20_transfer("token", "admin", 5));
+        assert_eq!(owner_of(&deps, 1), "alice");
+
+        // the token cannot be dropped while the fee is charged in it
+        let remove = BenchmarkExecuteMsg::RemoveAcceptedToken {
+            address: "token".to_string(),
+        };
+        let err = run(&mut deps, &env, "admin", &[], remove.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TokenInUse {}));
+        let msg = BenchmarkExecuteMsg::UpdateRegistrationFee { fee: None };
+        run(&mut deps, &env, "admin", &[], msg).unwrap();
+        run(&mut deps, &env, "admin", &[], remove.clone()).unwrap();
+        let err = run(&mut deps, &env, "admin", &[], remove).unwrap_err();
+        assert!(matches!(err, ContractError::TokenNotAccepted {}));
+    }
+
+    const YEAR: u64 = 365 * 24 * 60 * 60;
+
+    fn tax_policy(denom: Denom) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::UpdateTaxPolicy {
+            authority: "assessor".to_string(),
+            denom,
+            rates: vec![TaxRate {
+                land_use: LandUse::Residential,
+                rate: Decimal::percent(10),
+            }],
+            grace_period: 2 * YEAR,
+        }
+    }
+
+    fn assess(deps: &mut TestDeps, env: &Env, house_index: u64, value: u128) {
+        let msg = BenchmarkExecuteMsg::AssessProperty {
+            house_index,
+            assessed_value: Uint128::new(value),
+        };
+        run(deps, env, "assessor", &[], msg).unwrap();
+    }
+
+    fn outstanding_tax(deps: &TestDeps, env: &Env, house_index: u64) -> TaxResponse {
+        let msg = BenchmarkQueryMsg::OutstandingTax { house_index };
+        from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn property_tax_accrues_on_the_assessed_value() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let mut env = mock_env();
+        let pay = BenchmarkExecuteMsg::PayPropertyTax { house_index: 1 };
+        let err = run(&mut deps, &env, "alice", &coins(1, "uluna"), pay.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TaxNotConfigured {}));
+
+        let native = Denom::Native("uluna".to_string());
+        run(&mut deps, &env, "admin", &[], tax_policy(native.clone())).unwrap();
+        let msg = BenchmarkExecuteMsg::AssessProperty {
+            house_index: 1,
+            assessed_value: Uint128::new(1000),
+        };
+        let err = run(&mut deps, &env, "alice", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = run(&mut deps, &env, "alice", &coins(1, "uluna"), pay.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::PropertyNotAssessed {}));
+        assess(&mut deps, &env, 1, 1000);
+
+        env.block.time = env.block.time.plus_seconds(YEAR);
+        assert_eq!(
+            outstanding_tax(&deps, &env, 1).outstanding.amount.u128(),
+            100
+        );
+        let err = run(&mut deps, &env, "alice", &coins(40, "uusd"), pay.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidTaxPayment {}));
+        let err = run(&mut deps, &env, "alice", &coins(101, "uluna"), pay.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TaxOverpaid { .. }));
+        let res = run(&mut deps, &env, "alice", &coins(40, "uluna"), pay).unwrap();
+        assert_eq!(res.messages[0].msg, bank_send("assessor", 40, "uluna"));
+
+        // a new assessment keeps what accrued on the old value
+        assess(&mut deps, &env, 1, 2000);
+        env.block.time = env.block.time.plus_seconds(YEAR / 2);
+        let msg = BenchmarkQueryMsg::OutstandingTaxByOwner {
+            owner: "alice".to_string(),
+            start_after: None,
+            limit: None,
+        };
+        let res: OwnerTaxResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        assert_eq!(res.taxes.len(), 1);
+        assert_eq!(res.total.amount.u128(), 160);
+        assert_eq!(res.total.denom, native);
+    }
+
+    #[test]
+    fn tax_accounts_follow_the_land() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let mut env = mock_env();
+        run(
+            &mut deps,
+            &env,
+            "admin",
+            &[],
+            tax_policy(Denom::Native("uluna".to_string())),
+        )
+        .unwrap();
+        assess(&mut deps, &env, 1, 1000);
+        env.block.time = env.block.time.plus_seconds(YEAR);
+
+        // the assessment and the arrears are split by area
+        run(&mut deps, &env, "registrar", &[], subdivide(1, &[70, 30])).unwrap();
+        let msg = BenchmarkQueryMsg::OutstandingTax { house_index: 1 };
+        assert!(query(deps.as_ref(), env.clone(), msg).is_err());
+        let child = outstanding_tax(&deps, &env, 2);
+        assert_eq!(child.account.assessed_value.u128(), 700);
+        assert_eq!(child.outstanding.amount.u128(), 70);
+        let child = outstanding_tax(&deps, &env, 3);
+        assert_eq!(child.account.assessed_value.u128(), 300);
+        assert_eq!(child.outstanding.amount.u128(), 30);
+
+        // a demolished record stops accruing but its arrears stay owed
+        set_status(&mut deps, 3, PropertyStatus::Demolished).unwrap();
+        env.block.time = env.block.time.plus_seconds(YEAR);
+        assert_eq!(
+            outstanding_tax(&deps, &env, 3).outstanding.amount.u128(),
+            30
+        );
+        let msg = BenchmarkExecuteMsg::AssessProperty {
+            house_index: 3,
+            assessed_value: Uint128::new(300),
+        };
+        let err = run(&mut deps, &env, "assessor", &[], msg).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::PropertyNotActive {
+                status: PropertyStatus::Demolished
+            }
+        ));
+
+        // a merger adds the accounts back up
+        register(&mut deps, "4 main st", "alice");
+        assess(&mut deps, &env, 4, 100);
+        let msg = BenchmarkExecuteMsg::MergeProperties {
+            indices: vec![2, 4],
+            merged: parcel("2-4 main st", 170),
+        };
+        run(&mut deps, &env, "registrar", &[], msg).unwrap();
+        let merged = outstanding_tax(&deps, &env, 5);
+        assert_eq!(merged.account.assessed_value.u128(), 800);
+        assert_eq!(merged.outstanding.amount.u128(), 140);
+    }
+
+    #[test]
+    fn tax_denom_stays_fixed_once_assessed() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let msg = BenchmarkExecuteMsg::AddAcceptedToken {
+            address: "token".to_string(),
+        };
+        run(&mut deps, &env, "admin", &[], msg).unwrap();
+        let token = Denom::Cw20(Addr::unchecked("token"));
+        run(&mut deps, &env, "admin", &[], tax_policy(token.clone())).unwrap();
+        let remove = BenchmarkExecuteMsg::RemoveAcceptedToken {
+            address: "token".to_string(),
+        };
+        let err = run(&mut deps, &env, "admin", &[], remove).unwrap_err();
+        assert!(matches!(err, ContractError::TokenInUse {}));
+
+        assess(&mut deps, &env, 1, 1000);
+        let native = Denom::Native("uluna".to_string());
+        let err = run(&mut deps, &env, "admin", &[], tax_policy(native)).unwrap_err();
+        assert!(matches!(err, ContractError::TaxDenomLocked {}));
+        run(&mut deps, &env, "admin", &[], tax_policy(token)).unwrap();
+    }
+
+    #[test]
+    fn delinquent_titles_are_auctioned_in_tax_sales() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let mut env = mock_env();
+        let msg = BenchmarkExecuteMsg::UpdateTaxPolicy {
+            authority: "assessor".to_string(),
+            denom: Denom::Native("uluna".to_string()),
+            rates: vec![TaxRate {
+                land_use: LandUse::Residential,
+                rate: Decimal::percent(10),
+            }],
+            grace_period: YEAR / 2,
+        };
+        run(&mut deps, &env, "admin", &[], msg).unwrap();
+        assess(&mut deps, &env, 1, 1000);
+        let start = |duration: u64| BenchmarkExecuteMsg::StartTaxSale {
+            house_index: 1,
+            duration,
+        };
+        let err = run(&mut deps, &env, "assessor", &[], start(100)).unwrap_err();
+        assert!(matches!(err, ContractError::NotDelinquent {}));
+
+        env.block.time = env.block.time.plus_seconds(YEAR);
+        let err = run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap_err();
+        assert!(matches!(err, ContractError::PropertyTaxDelinquent {}));
+        let err = run(&mut deps, &env, "alice", &[], start(100)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = run(&mut deps, &env, "assessor", &[], start(u64::MAX)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidExpiration {}));
+        run(&mut deps, &env, "assessor", &[], start(100)).unwrap();
+        let err = run(&mut deps, &env, "assessor", &[], start(100)).unwrap_err();
+        assert!(matches!(err, ContractError::TaxSaleInProgress {}));
+
+        let bid = BenchmarkExecuteMsg::BidTaxSale { house_index: 1 };
+        let err = run(&mut deps, &env, "alice", &coins(100, "uluna"), bid.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TransferToSelf {}));
+        let err = run(&mut deps, &env, "bob", &coins(99, "uluna"), bid.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::BidTooLow { .. }));
+        run(&mut deps, &env, "bob", &coins(100, "uluna"), bid.clone()).unwrap();
+        let err = run(&mut deps, &env, "carol", &coins(100, "uluna"), bid.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::BidTooLow { .. }));
+        let res = run(&mut deps, &env, "carol", &coins(150, "uluna"), bid.clone()).unwrap();
+        assert_eq!(res.messages[0].msg, bank_send("bob", 100, "uluna"));
+
+        let close = BenchmarkExecuteMsg::CloseTaxSale { house_index: 1 };
+        let err = run(&mut deps, &env, "anyone", &[], close.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TaxSaleNotEnded {}));
+        env.block.time = env.block.time.plus_seconds(100);
+        let err = run(&mut deps, &env, "dave", &coins(200, "uluna"), bid).unwrap_err();
+        assert!(matches!(err, ContractError::TaxSaleEnded {}));
+
+        // the tax owed goes to the authority and the surplus to the former owner
+        let res = run(&mut deps, &env, "anyone", &[], close).unwrap();
+        assert_eq!(res.messages[0].msg, bank_send("assessor", 100, "uluna"));
+        assert_eq!(res.messages[1].msg, bank_send("alice", 50, "uluna"));
+        assert_eq!(owner_of(&deps, 1), "carol");
+        assert!(outstanding_tax(&deps, &env, 1).outstanding.amount.is_zero());
+    }
+
+    #[test]
+    fn paying_the_tax_redeems_a_title_in_tax_sale() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let mut env = mock_env();
+        let msg = BenchmarkExecuteMsg::UpdateTaxPolicy {
+            authority: "assessor".to_string(),
+            denom: Denom::Native("uluna".to_string()),
+            rates: vec![TaxRate {
+                land_use: LandUse::Residential,
+                rate: Decimal::percent(10),
+            }],
+            grace_period: 0,
+        };
+        run(&mut deps, &env, "admin", &[], msg).unwrap();
+        assess(&mut deps, &env, 1, 1000);
+        env.block.time = env.block.time.plus_seconds(YEAR);
+        let msg = BenchmarkExecuteMsg::StartTaxSale {
+            house_index: 1,
+            duration: 100,
+        };
+        run(&mut deps, &env, "assessor", &[], msg).unwrap();
+        let bid = BenchmarkExecuteMsg::BidTaxSale { house_index: 1 };
+        run(&mut deps, &env, "bob", &coins(100, "uluna"), bid).unwrap();
+
+        let pay = BenchmarkExecuteMsg::PayPropertyTax { house_index: 1 };
+        run(&mut deps, &env, "alice", &coins(100, "uluna"), pay).unwrap();
+        env.block.time = env.block.time.plus_seconds(100);
+        let close = BenchmarkExecuteMsg::CloseTaxSale { house_index: 1 };
+        let res = run(&mut deps, &env, "anyone", &[], close).unwrap();
+        assert_eq!(res.messages[0].msg, bank_send("bob", 100, "uluna"));
+        assert_eq!(owner_of(&deps, 1), "alice");
+    }
+
+    const MONTH: u64 = 30 * 24 * 60 * 60;
+
+    fn create_lease(start: Timestamp, period: u64, end: Timestamp) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::CreateLease {
+            house_index: 1,
+            tenant: "tenant".to_string(),
+            rent: Uint128::new(100),
+            denom: Denom::Native("uluna".to_string()),
+            period,
+            start,
+            end,
+            deposit: Uint128::zero(),
+        }
+    }
+
+    fn lease(deps: &TestDeps, house_index: u64, lease_id: u64) -> Lease {
+        let msg = BenchmarkQueryMsg::Lease {
+            house_index,
+            lease_id,
+        };
+        let res: LeaseResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.lease
+    }
+
+    #[test]
+    fn leases_need_a_full_first_period() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let start = env.block.time;
+        let err = run(
+            &mut deps,
+            &env,
+            "alice",
+            &[],
+            create_lease(start, 0, start.plus_seconds(MONTH)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidLeaseTerm {}));
+        let err = run(
+            &mut deps,
+            &env,
+            "alice",
+            &[],
+            create_lease(start, MONTH, start.plus_seconds(MONTH - 1)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidLeaseTerm {}));
+        let err = run(
+            &mut deps,
+            &env,
+            "alice",
+            &[],
+            create_lease(start, u64::MAX, start.plus_seconds(MONTH)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidLeaseTerm {}));
+        let err = run(
+            &mut deps,
+            &env,
+            "bob",
+            &[],
+            create_lease(start, MONTH, start.plus_seconds(MONTH)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        run(
+            &mut deps,
+            &env,
+            "alice",
+            &[],
+            create_lease(start, MONTH, start.plus_seconds(MONTH)),
+        )
+        .unwrap();
+        assert_eq!(lease(&deps, 1, 1).status, LeaseStatus::Proposed);
+    }
+
+    #[test]
+    fn rent_is_paid_to_the_current_owner() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let start = env.block.time;
+        // two full periods and a shorter third one
+        let end = start.plus_seconds(2 * MONTH + 10);
+        run(
+            &mut deps,
+            &env,
+            "alice",
+            &[],
+            create_lease(start, MONTH, end),
+        )
+        .unwrap();
+        let pay = BenchmarkExecuteMsg::PayRent {
+            house_index: 1,
+            lease_id: 1,
+        };
+        let err = run(&mut deps, &env, "tenant", &coins(100, "uluna"), pay.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::LeaseNotActive {}));
+
+        let accept = BenchmarkExecuteMsg::AcceptLease {
+            house_index: 1,
+            lease_id: 1,
+        };
+        let err = run(&mut deps, &env, "bob", &[], accept.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "tenant", &[], accept.clone()).unwrap();
+        let err = run(&mut deps, &env, "tenant", &[], accept).unwrap_err();
+        assert!(matches!(err, ContractError::LeaseNotProposed {}));
+
+        let err = run(&mut deps, &env, "tenant", &coins(150, "uluna"), pay.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidRentPayment { .. }));
+        let err = run(&mut deps, &env, "tenant", &coins(100, "uusd"), pay.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidRentPayment { .. }));
+        let err = run(&mut deps, &env, "tenant", &coins(400, "uluna"), pay.clone()).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::RentExceedsLease { periods: 3 }
+        ));
+
+        let res = run(&mut deps, &env, "tenant", &coins(200, "uluna"), pay.clone()).unwrap();
+        assert_eq!(res.messages[0].msg, bank_send("alice", 200, "uluna"));
+        assert_eq!(
+            lease(&deps, 1, 1).paid_through,
+            start.plus_seconds(2 * MONTH)
+        );
+
+        run(&mut deps, &env, "alice", &[], propose("bob", None)).unwrap();
+        let accept = BenchmarkExecuteMsg::AcceptTransfer { house_index: 1 };
+        run(&mut deps, &env, "bob", &[], accept).unwrap();
+        let res = run(&mut deps, &env, "tenant", &coins(100, "uluna"), pay.clone()).unwrap();
+        assert_eq!(res.messages[0].msg, bank_send("bob", 100, "uluna"));
+        assert_eq!(lease(&deps, 1, 1).paid_through, end);
+
+        let err = run(&mut deps, &env, "tenant", &coins(100, "uluna"), pay).unwrap_err();
+        assert!(matches!(err, ContractError::LeaseFullyPaid {}));
+    }
+
+    const WEEK: u64 = 7 * 24 * 60 * 60;
+
+    fn deposit_lease(deps: &mut TestDeps, env: &Env, end: Timestamp) {
+        let msg = BenchmarkExecuteMsg::CreateLease {
+            house_index: 1,
+            tenant: "tenant".to_string(),
+            rent: Uint128::new(100),
+            denom: Denom::Native("uluna".to_string()),
+            period: MONTH,
+            start: env.block.time,
+            end,
+            deposit: Uint128::new(300),
+        };
+        run(deps, env, "alice", &[], msg).unwrap();
+    }
+
+    fn claim_deposit(amount: u128) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::ClaimDeposit {
+            house_index: 1,
+            lease_id: 1,
+            amount: Uint128::new(amount),
+            reason: "broken window".to_string(),
+        }
+    }
+
+    #[test]
+    fn lease_deposits_are_escrowed_on_acceptance() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let end = env.block.time.plus_seconds(MONTH);
+        run(
+            &mut deps,
+            &env,
+            "alice",
+            &[],
+            create_lease(env.block.time, MONTH, end),
+        )
+        .unwrap();
+        deposit_lease(&mut deps, &env, end);
+        let accept = |lease_id: u64| BenchmarkExecuteMsg::AcceptLease {
+            house_index: 1,
+            lease_id,
+        };
+
+        let err = run(&mut deps, &env, "tenant", &coins(1, "uluna"), accept(1)).unwrap_err();
+        assert!(matches!(err, ContractError::UnexpectedPayment {}));
+        run(&mut deps, &env, "tenant", &[], accept(1)).unwrap();
+
+        let err = run(&mut deps, &env, "tenant", &[], accept(2)).unwrap_err();
+        assert!(matches!(err, ContractError::IncorrectPayment { .. }));
+        let err = run(&mut deps, &env, "tenant", &coins(299, "uluna"), accept(2)).unwrap_err();
+        assert!(matches!(err, ContractError::IncorrectPayment { .. }));
+        run(&mut deps, &env, "tenant", &coins(300, "uluna"), accept(2)).unwrap();
+        assert!(lease(&deps, 1, 2).holds_deposit());
+        assert!(!lease(&deps, 1, 1).holds_deposit());
+    }
+
+    #[test]
+    fn deposit_claims_are_paid_unless_rejected() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let mut env = mock_env();
+        let end = env.block.time.plus_seconds(MONTH);
+        deposit_lease(&mut deps, &env, end);
+        let accept = BenchmarkExecuteMsg::AcceptLease {
+            house_index: 1,
+            lease_id: 1,
+        };
+        run(&mut deps, &env, "tenant", &coins(300, "uluna"), accept).unwrap();
+
+        let err = run(&mut deps, &env, "alice", &[], claim_deposit(50)).unwrap_err();
+        assert!(matches!(err, ContractError::LeaseNotEnded {}));
+        env.block.time = end;
+        let err = run(&mut deps, &env, "tenant", &[], claim_deposit(50)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = run(&mut deps, &env, "alice", &[], claim_deposit(0)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidAmount {}));
+        let err = run(&mut deps, &env, "alice", &[], claim_deposit(301)).unwrap_err();
+        assert!(matches!(err, ContractError::ClaimExceedsDeposit { .. }));
+        run(&mut deps, &env, "alice", &[], claim_deposit(50)).unwrap();
+        run(&mut deps, &env, "alice", &[], claim_deposit(100)).unwrap();
+
+        let dispute = BenchmarkExecuteMsg::DisputeDepositClaim {
+            house_index: 1,
+            lease_id: 1,
+            claim_id: 2,
+        };
+        let err = run(&mut deps, &env, "alice", &[], dispute.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "tenant", &[], dispute.clone()).unwrap();
+        let err = run(&mut deps, &env, "tenant", &[], dispute).unwrap_err();
+        assert!(matches!(err, ContractError::ClaimNotPending {}));
+
+        let release = BenchmarkExecuteMsg::ReleaseDeposit {
+            house_index: 1,
+            lease_id: 1,
+        };
+        env.block.time = end.plus_seconds(2 * WEEK);
+        let err = run(&mut deps, &env, "alice", &[], claim_deposit(10)).unwrap_err();
+        assert!(matches!(err, ContractError::ClaimWindowClosed {}));
+        let err = run(&mut deps, &env, "anyone", &[], release.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::DepositNotReleasable {}));
+
+        let resolve = |uphold: bool| BenchmarkExecuteMsg::ResolveDepositClaim {
+            house_index: 1,
+            lease_id: 1,
+            claim_id: 2,
+            uphold,
+        };
+        let err = run(&mut deps, &env, "alice", &[], resolve(false)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "court", &[], resolve(false)).unwrap();
+        let err = run(&mut deps, &env, "court", &[], resolve(true)).unwrap_err();
+        assert!(matches!(err, ContractError::ClaimNotDisputed {}));
+
+        // only the undisputed claim is deducted
+        let res = run(&mut deps, &env, "anyone", &[], release.clone()).unwrap();
+        assert_eq!(res.messages.len(), 2);
+        assert_eq!(res.messages[0].msg, bank_send("alice", 50, "uluna"));
+        assert_eq!(res.messages[1].msg, bank_send("tenant", 250, "uluna"));
+        let err = run(&mut deps, &env, "anyone", &[], release).unwrap_err();
+        assert!(matches!(err, ContractError::DepositNotHeld {}));
+    }
+
+    fn list_for_sale(
+        house_index: u64,
+        price: u128,
+        expires: Option<Timestamp>,
+    ) -> BenchmarkExecuteMsg {
+        BenchmarkExecuteMsg::ListForSale {
+            house_index,
+            price: Uint128::new(price),
+            denom: Denom::Native("uluna".to_string()),
+            expires,
+        }
+    }
+
+    fn listings_for_sale(deps: &TestDeps, env: &Env, max_price: Option<u128>) -> Vec<u64> {
+        let msg = BenchmarkQueryMsg::ListingsForSale {
+            start_after: None,
+            limit: None,
+            max_price: max_price.map(|amount| Asset::from(coin(amount, "uluna"))),
+            land_use: None,
+        };
+        let res: ListingsResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+        res.listings
+            .iter()
+            .map(|listing| listing.house_index)
+            .collect()
+    }
+
+    #[test]
+    fn listed_properties_sell_at_the_asking_price() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let buy = BenchmarkExecuteMsg::BuyListedProperty { house_index: 1 };
+        let err = run(&mut deps, &env, "bob", &coins(100, "uluna"), buy.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::NotListed {}));
+        let err = run(&mut deps, &env, "bob", &[], list_for_sale(1, 100, None)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = run(
+            &mut deps,
+            &env,
+            "alice",
+            &[],
+            list_for_sale(1, 100, Some(env.block.time)),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidExpiration {}));
+        run(&mut deps, &env, "alice", &[], list_for_sale(1, 100, None)).unwrap();
+
+        let err = run(&mut deps, &env, "alice", &coins(100, "uluna"), buy.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TransferToSelf {}));
+        let err = run(&mut deps, &env, "bob", &coins(99, "uluna"), buy.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::IncorrectPayment { .. }));
+        let res = run(&mut deps, &env, "bob", &coins(100, "uluna"), buy.clone()).unwrap();
+        assert_eq!(res.messages[0].msg, bank_send("alice", 100, "uluna"));
+        assert_eq!(owner_of(&deps, 1), "bob");
+
+        // the listing goes with the old owner
+        let err = run(&mut deps, &env, "carol", &coins(100, "uluna"), buy).unwrap_err();
+        assert!(matches!(err, ContractError::NotListed {}));
+    }
+
+    #[test]
+    fn expired_listings_can_be_cleared_by_anyone() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        register(&mut deps, "2 main st", "alice");
+        let mut env = mock_env();
+        let expires = env.block.time.plus_seconds(100);
+        run(
+            &mut deps,
+            &env,
+            "alice",
+            &[],
+            list_for_sale(1, 100, Some(expires)),
+        )
+        .unwrap();
+        run(&mut deps, &env, "alice", &[], list_for_sale(2, 200, None)).unwrap();
+        assert_eq!(listings_for_sale(&deps, &env, None), vec![1, 2]);
+        assert_eq!(listings_for_sale(&deps, &env, Some(150)), vec![1]);
+
+        let delist = BenchmarkExecuteMsg::Delist { house_index: 1 };
+        let err = run(&mut deps, &env, "bob", &[], delist.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        env.block.time = expires;
+        assert_eq!(listings_for_sale(&deps, &env, None), vec![2]);
+        let buy = BenchmarkExecuteMsg::BuyListedProperty { house_index: 1 };
+        let err = run(&mut deps, &env, "bob", &coins(100, "uluna"), buy).unwrap_err();
+        assert!(matches!(err, ContractError::ListingExpired {}));
+        run(&mut deps, &env, "bob", &[], delist.clone()).unwrap();
+        let err = run(&mut deps, &env, "alice", &[], delist).unwrap_err();
+        assert!(matches!(err, ContractError::NotListed {}));
+    }
+
+    fn make_offer(deps: &mut TestDeps, env: &Env, buyer: &str, amount: u128) {
+        let msg = BenchmarkExecuteMsg::MakeOffer { house_index: 1 };
+        run(deps, env, buyer, &coins(amount, "uluna"), msg).unwrap();
+    }
+
+    fn offers(deps: &TestDeps) -> OffersResponse {
+        let msg = BenchmarkQueryMsg::OffersByProperty {
+            house_index: 1,
+            start_after: None,
+            limit: None,
+        };
+        from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+    }
+
+    fn offer_statuses(deps: &TestDeps) -> Vec<OfferStatus> {
+        offers(deps)
+            .offers
+            .iter()
+            .map(|offer| offer.status)
+            .collect()
+    }
+
+    #[test]
+    fn losing_offers_are_refunded_on_acceptance() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let msg = BenchmarkExecuteMsg::MakeOffer { house_index: 1 };
+        let err = run(&mut deps, &env, "alice", &coins(100, "uluna"), msg.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TransferToSelf {}));
+        let err = run(&mut deps, &env, "bob", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidPrice {}));
+        make_offer(&mut deps, &env, "bob", 100);
+        make_offer(&mut deps, &env, "carol", 120);
+        make_offer(&mut deps, &env, "dave", 90);
+
+        let withdraw = BenchmarkExecuteMsg::WithdrawOffer {
+            house_index: 1,
+            offer_id: 3,
+        };
+        let err = run(&mut deps, &env, "bob", &[], withdraw.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let res = run(&mut deps, &env, "dave", &[], withdraw.clone()).unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].msg, bank_send("dave", 90, "uluna"));
+        let err = run(&mut deps, &env, "dave", &[], withdraw).unwrap_err();
+        assert!(matches!(err, ContractError::OfferNotOpen {}));
+
+        let accept = BenchmarkExecuteMsg::AcceptOffer {
+            house_index: 1,
+            offer_id: 2,
+        };
+        let err = run(&mut deps, &env, "bob", &[], accept.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let res = run(&mut deps, &env, "alice", &[], accept).unwrap();
+        assert_eq!(res.messages.len(), 2);
+        assert_eq!(res.messages[0].msg, bank_send("alice", 120, "uluna"));
+        assert_eq!(res.messages[1].msg, bank_send("bob", 100, "uluna"));
+        assert_eq!(owner_of(&deps, 1), "carol");
+        assert_eq!(
+            offer_statuses(&deps),
+            vec![
+                OfferStatus::Lost,
+                OfferStatus::Accepted,
+                OfferStatus::Withdrawn
+            ]
+        );
+    }
+
+    #[test]
+    fn counter_offers_are_accepted_by_paying_the_difference() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        make_offer(&mut deps, &env, "bob", 100);
+        let counter = |price: u128| BenchmarkExecuteMsg::CounterOffer {
+            house_index: 1,
+            offer_id: 1,
+            price: Uint128::new(price),
+        };
+        let err = run(&mut deps, &env, "bob", &[], counter(150)).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        let err = run(&mut deps, &env, "alice", &[], counter(100)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidCounterOffer {}));
+        run(&mut deps, &env, "alice", &[], counter(150)).unwrap();
+
+        let accept = BenchmarkExecuteMsg::AcceptOffer {
+            house_index: 1,
+            offer_id: 1,
+        };
+        let err = run(&mut deps, &env, "bob", &coins(40, "uluna"), accept.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::IncorrectPayment { .. }));
+        let res = run(&mut deps, &env, "bob", &coins(50, "uluna"), accept).unwrap();
+        assert_eq!(res.messages[0].msg, bank_send("alice", 150, "uluna"));
+        assert_eq!(owner_of(&deps, 1), "bob");
+    }
+
+    #[test]
+    fn open_offers_are_refunded_on_any_title_change() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        make_offer(&mut deps, &env, "bob", 100);
+        make_offer(&mut deps, &env, "carol", 120);
+        run(&mut deps, &env, "alice", &[], list_for_sale(1, 200, None)).unwrap();
+
+        let buy = BenchmarkExecuteMsg::BuyListedProperty { house_index: 1 };
+        let res = run(&mut deps, &env, "dave", &coins(200, "uluna"), buy).unwrap();
+        assert_eq!(res.messages.len(), 3);
+        assert_eq!(res.messages[0].msg, bank_send("alice", 200, "uluna"));
+        assert_eq!(res.messages[1].msg, bank_send("bob", 100, "uluna"));
+        assert_eq!(res.messages[2].msg, bank_send("carol", 120, "uluna"));
+        assert_eq!(
+            offer_statuses(&deps),
+            vec![OfferStatus::Lost, OfferStatus::Lost]
+        );
+
+        // a transfer without payment refunds them just the same
+        make_offer(&mut deps, &env, "bob", 110);
+        run(&mut deps, &env, "dave", &[], propose("erin", None)).unwrap();
+        let accept = BenchmarkExecuteMsg::AcceptTransfer { house_index: 1 };
+        let res = run(&mut deps, &env, "erin", &[], accept).unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].msg, bank_send("bob", 110, "uluna"));
+        let withdraw = BenchmarkExecuteMsg::WithdrawOffer {
+            house_index: 1,
+            offer_id: 3,
+        };
+        let err = run(&mut deps, &env, "bob", &[], withdraw).unwrap_err();
+        assert!(matches!(err, ContractError::OfferNotOpen {}));
+    }
+
+    #[test]
+    fn offers_respect_the_minimum_and_the_cap() {
+        let mut deps = setup();
+        register(&mut deps, "1 main st", "alice");
+        let env = mock_env();
+        let minimum = |minimum: Option<u128>| BenchmarkExecuteMsg::SetMinimumOffer {
+            house_index: 1,
+            minimum: minimum.map(|amount| Asset::from(coin(amount, "uluna"))),
+        };
+        let err = run(&mut deps, &env, "bob", &[], minimum(Some(100))).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        run(&mut deps, &env, "alice", &[], minimum(Some(100))).unwrap();
+        let msg = BenchmarkExecuteMsg::MakeOffer { house_index: 1 };
+        let err = run(&mut deps, &env, "bob", &coins(99, "uluna"), msg.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::OfferTooLow { .. }));
+        let err = run(&mut deps, &env, "bob", &coins(100, "uusd"), msg.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::OfferTooLow { .. }));
+        run(&mut deps, &env, "alice", &[], minimum(None)).unwrap();
+        assert_eq!(offers(&deps).minimum, None);
+
+        for _ in 0..MAX_OPEN_OFFERS {
+            make_offer(&mut deps, &env, "bob", 10);
+        }
+        let err = run(&mut deps, &env, "carol", &coins(10, "uluna"), msg.clone()).unwrap_err();
+        assert!(matches!(err, ContractError::TooManyOffers { .. }));
+        let withdraw = BenchmarkExecuteMsg::WithdrawOffer {
+            house_index: 1,
+            offer_id: 1,
+        };
+        run(&mut deps, &env, "bob", &[], withdraw).unwrap();
+        run(&mut deps, &env, "carol", &coins(10, "uluna"), msg).unwrap();
+    }
 }
@@ -1,6 +1,8 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use thiserror::Error;
 
+use crate::state::{Asset, PropertyStatus};
+
 #[derive(Error, Debug)]
 pub enum ContractError {
     #[error("{0}")]
@@ -9,14 +11,183 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("The Address is already a Registrar")]
+    RegistrarAlreadyExists {},
+    #[error("The Address is not a Registrar")]
+    RegistrarDoesNotExist {},
+    #[error("A Transfer is already pending for this Real Estate")]
+    TransferAlreadyPending {},
+    #[error("There is no pending Transfer for this Real Estate")]
+    NoPendingTransfer {},
+    #[error("The Transfer offer has expired")]
+    TransferExpired {},
+    #[error("The Expiration is already in the past")]
+    InvalidExpiration {},
+    #[error("Cannot transfer a Real Estate to its current owner")]
+    TransferToSelf {},
+    #[error("A Sale is in progress for this Real Estate")]
+    SaleInProgress {},
+    #[error("There is no Sale in progress for this Real Estate")]
+    NoSaleInProgress {},
+    #[error("The Sale has already been funded")]
+    SaleAlreadyFunded {},
+    #[error("The Sale has not been funded yet")]
+    SaleNotFunded {},
+    #[error("The Price must be greater than zero")]
+    InvalidPrice {},
+    #[error("The Funds sent must be exactly {expected}")]
+    IncorrectPayment { expected: Asset },
+    #[error("No Funds are expected")]
+    UnexpectedPayment {},
+    #[error("The Token is not accepted as payment")]
+    TokenNotAccepted {},
+    #[error("The Token is already accepted as payment")]
+    TokenAlreadyAccepted {},
+    #[error("The Token is still configured for the registration fee or property tax")]
+    TokenInUse {},
+
+    #[error("The Address is already an Arbitrator")]
+    ArbitratorAlreadyExists {},
+    #[error("The Address is not an Arbitrator")]
+    ArbitratorDoesNotExist {},
+
     #[error("The Operation cannot be performed due to insufficient funds")]
     InsufficientFunds {},
     #[error("The Validator does not exist in the Blockchain")]
     ValidatorDoesNotExist {},
     #[error("The Real Estate does not Exist")]
     RealEstateDoesNotExist {},
-    
-
+    #[error("The Real Estate is already registered")]
+    RealEstateAlreadyExists {},
+    #[error("The Parcel Id is already registered")]
+    ParcelAlreadyExists {},
+    #[error("The Area of a Real Estate must be greater than zero")]
+    InvalidArea {},
+    #[error("The Coordinates are out of range")]
+    InvalidCoordinates {},
+    #[error("The Lien does not exist")]
+    LienDoesNotExist {},
+    #[error("The Lien has already been released")]
+    LienAlreadyReleased {},
+    #[error("The Lien {lien_id} must be released or its holder must consent to the transfer")]
+    LienOutstanding { lien_id: u64 },
+    #[error("The Amount must be greater than zero")]
+    InvalidAmount {},
+    #[error("The Encumbrance does not exist")]
+    EncumbranceDoesNotExist {},
+    #[error("The Encumbrance has already been released")]
+    EncumbranceAlreadyReleased {},
+    #[error("The Document Hash must be a hex encoded sha256 digest")]
+    InvalidDocumentHash {},
+    #[error("A Real Estate cannot be its own beneficiary")]
+    InvalidBeneficiary {},
+    #[error("The Real Estate is {status}, it must be active")]
+    PropertyNotActive { status: PropertyStatus },
+    #[error("The Real Estate cannot go from {from} to {to}")]
+    InvalidStatusTransition {
+        from: PropertyStatus,
+        to: PropertyStatus,
+    },
+    #[error("A Subdivision needs at least two child parcels")]
+    TooFewChildParcels {},
+    #[error("The child parcels cover {actual} square meters instead of {expected}")]
+    AreaMismatch { expected: u64, actual: u64 },
+    #[error("A Merge needs at least two distinct parcels")]
+    TooFewParcelsToMerge {},
+    #[error("All parcels must have the same owner")]
+    OwnerMismatch {},
+    #[error("There is no open Dispute for this Real Estate")]
+    NoOpenDispute {},
+    #[error("There is no open Registration Request for this Real Estate")]
+    NoOpenRegistration {},
+    #[error("The Registrar has already voted on this Registration Request")]
+    AlreadyVoted {},
+    #[error("The Registration Request has expired")]
+    RegistrationExpired {},
+    #[error("The Registration Request has not expired yet")]
+    RegistrationNotExpired {},
+    #[error("The Approval Threshold must be between 1 and the number of Registrars")]
+    InvalidThreshold {},
+    #[error("A Subdivision or Merger of this Real Estate is awaiting approval")]
+    RestructuringPending {},
+    #[error("The Document is already anchored to this Real Estate")]
+    DocumentAlreadyAnchored {},
+    #[error("The Title Transfer does not exist")]
+    TitleTransferDoesNotExist {},
+    #[error("No Property Tax policy has been configured")]
+    TaxNotConfigured {},
+    #[error("The Real Estate has not been assessed")]
+    PropertyNotAssessed {},
+    #[error("The Tax must be paid with a single non-zero amount of the tax denom")]
+    InvalidTaxPayment {},
+    #[error("The Payment exceeds the outstanding Tax of {due}")]
+    TaxOverpaid { due: Uint128 },
+    #[error("The Tax denom cannot change once properties are assessed")]
+    TaxDenomLocked {},
+    #[error("The Real Estate is delinquent on its Property Tax")]
+    PropertyTaxDelinquent {},
+    #[error("The Real Estate is not delinquent on its Property Tax")]
+    NotDelinquent {},
+    #[error("A Tax Sale is in progress for this Real Estate")]
+    TaxSaleInProgress {},
+    #[error("There is no Tax Sale in progress for this Real Estate")]
+    NoTaxSaleInProgress {},
+    #[error("The Tax Sale has ended")]
+    TaxSaleEnded {},
+    #[error("The Tax Sale has not ended yet")]
+    TaxSaleNotEnded {},
+    #[error("The Bid must be at least {minimum}")]
+    BidTooLow { minimum: Uint128 },
+    #[error("The Lease does not exist")]
+    LeaseDoesNotExist {},
+    #[error("The Lease must run for at least one period and end in the future")]
+    InvalidLeaseTerm {},
+    #[error("The Lease is not waiting for acceptance")]
+    LeaseNotProposed {},
+    #[error("The Lease is not active")]
+    LeaseNotActive {},
+    #[error("The Rent has been paid up to the end of the Lease")]
+    LeaseFullyPaid {},
+    #[error("Rent must be paid in whole periods of {rent}")]
+    InvalidRentPayment { rent: Asset },
+    #[error("Only {periods} periods of Rent are left on the Lease")]
+    RentExceedsLease { periods: u64 },
+    #[error("The Lease holds no Deposit")]
+    DepositNotHeld {},
+    #[error("The Lease has not ended yet")]
+    LeaseNotEnded {},
+    #[error("The period to claim deductions has closed")]
+    ClaimWindowClosed {},
+    #[error("The period to dispute the Claim has closed")]
+    DisputeWindowClosed {},
+    #[error("The Claims exceed the Deposit, {available} is left to claim")]
+    ClaimExceedsDeposit { available: Uint128 },
+    #[error("The Deposit Claim does not exist")]
+    DepositClaimDoesNotExist {},
+    #[error("The Deposit Claim is not pending")]
+    ClaimNotPending {},
+    #[error("The Deposit Claim is not disputed")]
+    ClaimNotDisputed {},
+    #[error("The Deposit cannot be released before every Claim is settled")]
+    DepositNotReleasable {},
+    #[error("The Real Estate is not listed for sale")]
+    NotListed {},
+    #[error("The Listing has expired")]
+    ListingExpired {},
+    #[error("The Offer does not exist")]
+    OfferDoesNotExist {},
+    #[error("The Offer is no longer open")]
+    OfferNotOpen {},
+    #[error("The Counter Offer must be above the Offer")]
+    InvalidCounterOffer {},
+    #[error("The Offer must be at least {minimum}")]
+    OfferTooLow { minimum: Asset },
+    #[error("The Real Estate already has {max} open Offers")]
+    TooManyOffers { max: u32 },
+    #[error("The Approval has expired")]
+    Expired {},
+    #[error("The Approval does not exist")]
+    ApprovalDoesNotExist {},
     // Add any other custom errors you like here.
     // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
 }
@@ -1,16 +1,334 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{to_binary, Addr, Binary, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg};
+use cw0::Expiration;
+use cw20::{Cw20ReceiveMsg, Denom};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::state::{
+    Asset, Beneficiary, Config, Coordinates, Dispute, DisputeOutcome, Document, DocumentType,
+    Encumbrance, EncumbranceKind, LandUse, Lease, Lien, Listing, Offer, PendingTransfer, Property,
+    PropertyStatus, RegistrationRequest, Sale, TaxAccount, TaxPolicy, TaxRate, TaxSale,
+    TitleTransfer,
+};
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct InstantiateMsg {}
+pub struct InstantiateMsg {
+    /// defaults to the instantiating address
+    pub admin: Option<String>,
+    pub registrars: Vec<String>,
+    pub arbitrators: Vec<String>,
+    /// defaults to a single registrar
+    pub approval_threshold: Option<u32>,
+    /// defaults to one week
+    pub registration_ttl: Option<u64>,
+    /// defaults to two weeks
+    pub deposit_dispute_window: Option<u64>,
+    /// CW20 contracts accepted as payment
+    pub accepted_tokens: Vec<String>,
+    pub registration_fee: Option<Asset>,
+}
+
+/// Description of a parcel to be registered
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ParcelInfo {
+    pub parcel_id: String,
+    pub house_address: String,
+    pub area: u64,
+    pub land_use: LandUse,
+    pub coordinates: Coordinates,
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BenchmarkExecuteMsg {
-
-    PushRealEstateToBlockchain {house_address:String},
-    ChangeOwnerofRealEstate{house_address:String,owner_name:String},
+    PushRealEstateToBlockchain {
+        parcel_id: String,
+        house_address: String,
+        area: u64,
+        land_use: LandUse,
+        coordinates: Coordinates,
+        owner: String,
+    },
+    ChangeOwnerofRealEstate {
+        house_address: String,
+        new_owner: String,
+        memo: Option<String>,
+    },
+    ProposeTransfer {
+        house_index: u64,
+        recipient: String,
+        expires: Option<Timestamp>,
+        memo: Option<String>,
+    },
+    AcceptTransfer {
+        house_index: u64,
+    },
+    CancelTransfer {
+        house_index: u64,
+    },
+    RejectTransfer {
+        house_index: u64,
+    },
+    OpenSale {
+        house_index: u64,
+        price: Uint128,
+        /// a native denom or an accepted CW20 contract
+        denom: Denom,
+        buyer: Option<String>,
+    },
+    DepositSaleFunds {
+        house_index: u64,
+    },
+    CloseSale {
+        house_index: u64,
+    },
+    CancelSale {
+        house_index: u64,
+    },
+    RegisterLien {
+        house_index: u64,
+        lienholder: String,
+        amount: Uint128,
+        denom: String,
+    },
+    ReleaseLien {
+        house_index: u64,
+        lien_id: u64,
+    },
+    ConsentToTransfer {
+        house_index: u64,
+        lien_id: u64,
+    },
+    AddEncumbrance {
+        house_index: u64,
+        kind: EncumbranceKind,
+        beneficiary: Beneficiary,
+        terms: String,
+        document_hash: String,
+    },
+    ReleaseEncumbrance {
+        house_index: u64,
+        encumbrance_id: u64,
+    },
+    SubdivideProperty {
+        house_index: u64,
+        children: Vec<ParcelInfo>,
+    },
+    MergeProperties {
+        indices: Vec<u64>,
+        merged: ParcelInfo,
+    },
+    ChangePropertyStatus {
+        house_index: u64,
+        status: PropertyStatus,
+    },
+    FreezeProperty {
+        house_index: u64,
+        reason: String,
+        case_ref: String,
+    },
+    ResolveDispute {
+        house_index: u64,
+        outcome: DisputeOutcome,
+    },
+    ApproveRegistration {
+        house_index: u64,
+    },
+    RejectRegistration {
+        house_index: u64,
+    },
+    ExpireRegistration {
+        house_index: u64,
+    },
+    UpdateRegistrationPolicy {
+        approval_threshold: u32,
+        registration_ttl: u64,
+    },
+    AnchorDocument {
+        house_index: u64,
+        sha256: String,
+        uri: String,
+        document_type: DocumentType,
+        transfer_sequence: Option<u64>,
+    },
+    /// Admin only, new rates apply to each property from its next
+    /// assessment, the denom is fixed once a property has been assessed
+    UpdateTaxPolicy {
+        authority: String,
+        denom: Denom,
+        rates: Vec<TaxRate>,
+        grace_period: u64,
+    },
+    /// Tax authority only
+    AssessProperty {
+        house_index: u64,
+        assessed_value: Uint128,
+    },
+    /// Pays part or all of the outstanding tax with the funds sent
+    PayPropertyTax {
+        house_index: u64,
+    },
+    /// Tax authority only, auctions a delinquent property for `duration` seconds
+    StartTaxSale {
+        house_index: u64,
+        duration: u64,
+    },
+    /// Bids the funds sent, the previous highest bid is refunded
+    BidTaxSale {
+        house_index: u64,
+    },
+    /// Settles an ended auction, anyone may call it
+    CloseTaxSale {
+        house_index: u64,
+    },
+    /// Owner only, offers a lease to `tenant`
+    CreateLease {
+        house_index: u64,
+        tenant: String,
+        rent: Uint128,
+        denom: Denom,
+        /// seconds between rent payments
+        period: u64,
+        start: Timestamp,
+        end: Timestamp,
+        deposit: Uint128,
+    },
+    /// Tenant only, the deposit is sent along and held by the contract
+    AcceptLease {
+        house_index: u64,
+        lease_id: u64,
+    },
+    /// Pays one or more periods of rent to the current owner
+    PayRent {
+        house_index: u64,
+        lease_id: u64,
+    },
+    /// Owner only, once the lease has ended
+    ClaimDeposit {
+        house_index: u64,
+        lease_id: u64,
+        amount: Uint128,
+        reason: String,
+    },
+    /// Tenant only, sends the claim to the arbitrators
+    DisputeDepositClaim {
+        house_index: u64,
+        lease_id: u64,
+        claim_id: u64,
+    },
+    /// Arbitrator only
+    ResolveDepositClaim {
+        house_index: u64,
+        lease_id: u64,
+        claim_id: u64,
+        uphold: bool,
+    },
+    /// Pays the claims to the owner and the remainder to the tenant once the
+    /// dispute window has passed, anyone may call it
+    ReleaseDeposit {
+        house_index: u64,
+        lease_id: u64,
+    },
+    /// Owner only, lists the property on the marketplace at an asking price
+    ListForSale {
+        house_index: u64,
+        price: Uint128,
+        denom: Denom,
+        expires: Option<Timestamp>,
+    },
+    /// The seller can delist at any time, anyone can clear an expired listing
+    Delist {
+        house_index: u64,
+    },
+    /// Buys a listed property by paying the asking price
+    BuyListedProperty {
+        house_index: u64,
+    },
+    /// Offers the funds sent for the property, they stay in escrow until the
+    /// offer is accepted or withdrawn, or the title changes hands
+    MakeOffer {
+        house_index: u64,
+    },
+    /// Owner only, asks a higher price for an open offer
+    CounterOffer {
+        house_index: u64,
+        offer_id: u64,
+        price: Uint128,
+    },
+    /// The owner accepts the offer, or the buyer accepts the counter offer by
+    /// sending the difference
+    AcceptOffer {
+        house_index: u64,
+        offer_id: u64,
+    },
+    /// Owner only, offers below `minimum` or in another denom are refused,
+    /// `None` accepts any offer again
+    SetMinimumOffer {
+        house_index: u64,
+        minimum: Option<Asset>,
+    },
+    /// Buyer only, refunds an open offer
+    WithdrawOffer {
+        house_index: u64,
+        offer_id: u64,
+    },
+    /// CW721: move the title token to `recipient`
+    TransferNft {
+        recipient: String,
+        token_id: String,
+    },
+    /// CW721: move the title token to a contract and notify it
+    SendNft {
+        contract: String,
+        token_id: String,
+        msg: Binary,
+    },
+    /// CW721: allow `spender` to transfer the title token
+    Approve {
+        spender: String,
+        token_id: String,
+        expires: Option<Expiration>,
+    },
+    /// CW721: remove a previously granted approval
+    Revoke {
+        spender: String,
+        token_id: String,
+    },
+    /// CW721: allow `operator` to transfer all title tokens of the sender
+    ApproveAll {
+        operator: String,
+        expires: Option<Expiration>,
+    },
+    /// CW721: remove a previously granted operator
+    RevokeAll {
+        operator: String,
+    },
+    AddRegistrar {
+        address: String,
+    },
+    RemoveRegistrar {
+        address: String,
+    },
+    AddArbitrator {
+        address: String,
+    },
+    RemoveArbitrator {
+        address: String,
+    },
+    TransferAdmin {
+        new_admin: String,
+    },
+    AddAcceptedToken {
+        address: String,
+    },
+    RemoveAcceptedToken {
+        address: String,
+    },
+    UpdateRegistrationFee {
+        fee: Option<Asset>,
+    },
+    /// Payment with an accepted CW20 token, `msg` is a `ReceiveMsg`
+    Receive(Cw20ReceiveMsg),
     AddValidator {
         validator_addr: Addr,
         vault_denom: String,
@@ -30,9 +348,377 @@ pub enum BenchmarkExecuteMsg {
     },
 }
 
+/// Actions paid for with the CW20 tokens sent along a `Receive`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiveMsg {
+    PushRealEstateToBlockchain {
+        parcel_id: String,
+        house_address: String,
+        area: u64,
+        land_use: LandUse,
+        coordinates: Coordinates,
+        owner: String,
+    },
+    DepositSaleFunds {
+        house_index: u64,
+    },
+    PayPropertyTax {
+        house_index: u64,
+    },
+    BidTaxSale {
+        house_index: u64,
+    },
+    AcceptLease {
+        house_index: u64,
+        lease_id: u64,
+    },
+    PayRent {
+        house_index: u64,
+        lease_id: u64,
+    },
+    BuyListedProperty {
+        house_index: u64,
+    },
+    MakeOffer {
+        house_index: u64,
+    },
+    AcceptOffer {
+        house_index: u64,
+        offer_id: u64,
+    },
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BenchmarkQueryMsg {
-    FindOwnerByHouseIndex {house_index:u64},
-    FindOwnerByHouseName {house_name:String},
+    Config {},
+    FindOwnerByHouseIndex {
+        house_index: u64,
+    },
+    FindOwnerByHouseName {
+        house_name: String,
+    },
+    FindPropertyByHouseIndex {
+        house_index: u64,
+    },
+    FindPropertyByHouseName {
+        house_name: String,
+    },
+    ListProperties {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        status: Option<PropertyStatus>,
+    },
+    PropertiesByOwner {
+        owner: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        status: Option<PropertyStatus>,
+    },
+    PendingTransfer {
+        house_index: u64,
+    },
+    Sale {
+        house_index: u64,
+    },
+    ListLiens {
+        house_index: u64,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    DisputeHistory {
+        house_index: u64,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    RegistrationRequest {
+        house_index: u64,
+    },
+    OpenRegistrationRequests {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    ListDocuments {
+        house_index: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    VerifyDocument {
+        house_index: u64,
+        sha256: String,
+    },
+    TaxPolicy {},
+    OutstandingTax {
+        house_index: u64,
+    },
+    /// Outstanding tax on the assessed properties of `owner`
+    OutstandingTaxByOwner {
+        owner: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    TaxSale {
+        house_index: u64,
+    },
+    Lease {
+        house_index: u64,
+        lease_id: u64,
+    },
+    ActiveLeases {
+        house_index: u64,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// `start_after` is a (house_index, lease_id) pair
+    ActiveLeasesByTenant {
+        tenant: String,
+        start_after: Option<(u64, u64)>,
+        limit: Option<u32>,
+    },
+    Listing {
+        house_index: u64,
+    },
+    /// Listings that have not expired, optionally at most `max_price` in its
+    /// denom and of a given land use
+    ListingsForSale {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        max_price: Option<Asset>,
+        land_use: Option<LandUse>,
+    },
+    OffersByProperty {
+        house_index: u64,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// CW721: owner of the title token and its approvals
+    OwnerOf {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    /// CW721: operators allowed to transfer all tokens of `owner`
+    ApprovedForAll {
+        owner: String,
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// CW721: number of title tokens, pending, retired and demolished records
+    /// have none
+    NumTokens {},
+    /// CW721: collection metadata
+    ContractInfo {},
+    /// CW721: metadata of a title token, the property record is the extension
+    NftInfo {
+        token_id: String,
+    },
+    /// CW721: owner and metadata of a title token
+    AllNftInfo {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    /// CW721: title tokens held by `owner`
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// CW721: every title token
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    TitleHistory {
+        house_index: u64,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigResponse {
+    pub config: Config,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PropertyResponse {
+    pub property: Property,
+    pub encumbrances: Vec<Encumbrance>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PropertiesResponse {
+    pub properties: Vec<Property>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TitleHistoryResponse {
+    pub history: Vec<TitleTransfer>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingTransferResponse {
+    pub pending_transfer: Option<PendingTransfer>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SaleResponse {
+    pub sale: Option<Sale>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LiensResponse {
+    pub liens: Vec<Lien>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DisputesResponse {
+    pub disputes: Vec<Dispute>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RegistrationRequestResponse {
+    pub request: Option<RegistrationRequest>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RegistrationRequestsResponse {
+    pub requests: Vec<RegistrationRequest>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DocumentsResponse {
+    pub documents: Vec<Document>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VerifyDocumentResponse {
+    pub anchored: bool,
+    pub document: Option<Document>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TaxPolicyResponse {
+    pub policy: Option<TaxPolicy>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TaxResponse {
+    pub account: TaxAccount,
+    pub outstanding: Asset,
+    /// part of the outstanding tax past the grace period
+    pub overdue: Asset,
+    pub delinquent: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OwnerTaxResponse {
+    pub taxes: Vec<TaxResponse>,
+    /// sum over the properties of this page
+    pub total: Asset,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TaxSaleResponse {
+    pub sale: Option<TaxSale>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LeaseResponse {
+    pub lease: Lease,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LeasesResponse {
+    pub leases: Vec<Lease>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListingResponse {
+    pub listing: Option<Listing>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListingsResponse {
+    pub listings: Vec<Listing>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OffersResponse {
+    pub minimum: Option<Asset>,
+    pub offers: Vec<Offer>,
+}
+
+/// CW721 approval, as seen by wallets and explorers
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Approval {
+    pub spender: String,
+    pub expires: Expiration,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OwnerOfResponse {
+    pub owner: String,
+    pub approvals: Vec<Approval>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ApprovedForAllResponse {
+    pub operators: Vec<Approval>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NumTokensResponse {
+    pub count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ContractInfoResponse {
+    pub name: String,
+    pub symbol: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NftInfoResponse {
+    pub token_uri: Option<String>,
+    pub extension: Property,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AllNftInfoResponse {
+    pub access: OwnerOfResponse,
+    pub info: NftInfoResponse,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TokensResponse {
+    pub tokens: Vec<String>,
+}
+
+/// Sent to the receiving contract of a SendNft
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Cw721ReceiveMsg {
+    pub sender: String,
+    pub token_id: String,
+    pub msg: Binary,
+}
+
+impl Cw721ReceiveMsg {
+    pub fn into_cosmos_msg(self, contract_addr: String) -> StdResult<CosmosMsg> {
+        let msg = to_binary(&ReceiverExecuteMsg::ReceiveNft(self))?;
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum ReceiverExecuteMsg {
+    ReceiveNft(Cw721ReceiveMsg),
 }
@@ -1,17 +1,675 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
-use cosmwasm_std::Uint128;
-
-use cw_storage_plus::{Item, Map, U64Key};
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128};
+use cw0::Expiration;
+use cw20::Denom;
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, U64Key, UniqueIndex};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct State {
     pub num_of_real_state: u64,
-    pub address_of_real_estate: Vec<(u64,String)>,
+    /// properties whose status makes them a title token
+    pub num_tokens: u64,
 }
 
 pub const STATE: Item<State> = Item::new("state");
 
-pub const owner_of_registry_index: Map<(u64, String), String> = Map::new("owner_of_registry");
-// pub const owner_of_registry_address: Map<(u64, String), String> = Map::new("owner_of_registry");
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    pub admin: Addr,
+    pub registrars: Vec<Addr>,
+    /// courts or arbitrators allowed to freeze contested titles
+    pub arbitrators: Vec<Addr>,
+    /// number of distinct registrars needed to activate a registration
+    pub approval_threshold: u32,
+    /// seconds a registration request stays open for approval
+    pub registration_ttl: u64,
+    /// CW20 contracts accepted as payment for sales and fees
+    pub accepted_tokens: Vec<Addr>,
+    /// charged for every new registration, forwarded to the admin
+    pub registration_fee: Option<Asset>,
+    /// seconds after a lease ends for deductions to be claimed, and after
+    /// each claim for the tenant to dispute it
+    pub deposit_dispute_window: u64,
+}
+
+impl Config {
+    pub fn is_registrar(&self, addr: &Addr) -> bool {
+        self.registrars.iter().any(|registrar| registrar == addr)
+    }
+
+    pub fn is_arbitrator(&self, addr: &Addr) -> bool {
+        self.arbitrators.iter().any(|arbitrator| arbitrator == addr)
+    }
+
+    /// Every registration must be able to reach the approval threshold.
+    pub fn has_valid_threshold(&self) -> bool {
+        self.approval_threshold >= 1 && self.approval_threshold as usize <= self.registrars.len()
+    }
+
+    pub fn accepts_token(&self, addr: &Addr) -> bool {
+        self.accepted_tokens.iter().any(|token| token == addr)
+    }
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// An amount of a native coin or of a CW20 token
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Asset {
+    pub denom: Denom,
+    pub amount: Uint128,
+}
+
+impl From<Coin> for Asset {
+    fn from(coin: Coin) -> Self {
+        Asset {
+            denom: Denom::Native(coin.denom),
+            amount: coin.amount,
+        }
+    }
+}
+
+impl fmt::Display for Asset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.denom {
+            Denom::Native(denom) => write!(f, "{}{}", self.amount, denom),
+            Denom::Cw20(token) => write!(f, "{} {}", self.amount, token),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LandUse {
+    Residential,
+    Commercial,
+    Industrial,
+    Agricultural,
+    MixedUse,
+    Public,
+    Vacant,
+}
+
+/// Geographic position of the parcel in micro-degrees, so that it can be
+/// stored without floating point.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Coordinates {
+    pub latitude: i64,
+    pub longitude: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PropertyStatus {
+    /// registered but not yet in force
+    Pending,
+    Active,
+    Frozen,
+    /// replaced by the properties it was split or merged into
+    Retired,
+    Demolished,
+    UnderDispute,
+}
+
+impl PropertyStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PropertyStatus::Pending => "pending",
+            PropertyStatus::Active => "active",
+            PropertyStatus::Frozen => "frozen",
+            PropertyStatus::Retired => "retired",
+            PropertyStatus::Demolished => "demolished",
+            PropertyStatus::UnderDispute => "under_dispute",
+        }
+    }
+
+    /// A title token exists from the moment the property is in force until
+    /// its record is retired or demolished.
+    pub fn is_token(&self) -> bool {
+        !matches!(
+            self,
+            PropertyStatus::Pending | PropertyStatus::Retired | PropertyStatus::Demolished
+        )
+    }
+
+    /// Retired and Demolished records are final.
+    pub fn can_transition_to(&self, next: PropertyStatus) -> bool {
+        use PropertyStatus::*;
+        matches!(
+            (self, next),
+            (Pending, Active)
+                | (Pending, Retired)
+                | (Active, Frozen)
+                | (Active, UnderDispute)
+                | (Active, Retired)
+                | (Active, Demolished)
+                | (Frozen, Active)
+                | (Frozen, UnderDispute)
+                | (UnderDispute, Active)
+                | (UnderDispute, Frozen)
+        )
+    }
+}
+
+impl fmt::Display for PropertyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Property {
+    pub house_index: u64,
+    pub parcel_id: String,
+    pub house_address: String,
+    /// area in square meters
+    pub area: u64,
+    pub land_use: LandUse,
+    pub coordinates: Coordinates,
+    pub registered_at_height: u64,
+    pub registered_at: Timestamp,
+    pub owner: Addr,
+    /// properties this one was split or merged from
+    pub parents: Vec<u64>,
+    /// properties this one was split or merged into
+    pub children: Vec<u64>,
+    pub status: PropertyStatus,
+}
+
+pub struct PropertyIndexes<'a> {
+    // a house address can only be registered once
+    pub house_address: UniqueIndex<'a, String, Property>,
+    pub parcel_id: UniqueIndex<'a, String, Property>,
+    // (owner, pk) -> pk, to list the properties held by an address
+    pub owner: MultiIndex<'a, (Vec<u8>, Vec<u8>), Property>,
+    pub status: MultiIndex<'a, (Vec<u8>, Vec<u8>), Property>,
+}
+
+impl<'a> IndexList<Property> for PropertyIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Property>> + '_> {
+        let v: Vec<&dyn Index<Property>> = vec![
+            &self.house_address,
+            &self.parcel_id,
+            &self.owner,
+            &self.status,
+        ];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn properties<'a>() -> IndexedMap<'a, U64Key, Property, PropertyIndexes<'a>> {
+    let indexes = PropertyIndexes {
+        house_address: UniqueIndex::new(
+            |d: &Property| d.house_address.clone(),
+            "property__house_address",
+        ),
+        parcel_id: UniqueIndex::new(|d: &Property| d.parcel_id.clone(), "property__parcel_id"),
+        owner: MultiIndex::new(
+            |d: &Property, k: Vec<u8>| (d.owner.as_bytes().to_vec(), k),
+            "property",
+            "property__owner",
+        ),
+        status: MultiIndex::new(
+            |d: &Property, k: Vec<u8>| (d.status.as_str().as_bytes().to_vec(), k),
+            "property",
+            "property__status",
+        ),
+    };
+    IndexedMap::new("property", indexes)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TitleTransfer {
+    pub sequence: u64,
+    /// `None` for the original registration
+    pub previous_owner: Option<Addr>,
+    pub new_owner: Addr,
+    pub block_height: u64,
+    pub time: Timestamp,
+    pub memo: Option<String>,
+}
+
+// (house_index, sequence) -> title transfer
+pub const TITLE_HISTORY: Map<(U64Key, U64Key), TitleTransfer> = Map::new("title_history");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingTransfer {
+    pub house_index: u64,
+    pub from: Addr,
+    pub to: Addr,
+    pub proposed_at: Timestamp,
+    pub expires: Option<Timestamp>,
+    pub memo: Option<String>,
+}
+
+impl PendingTransfer {
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        self.expires.map_or(false, |expires| now >= expires)
+    }
+}
+
+// house_index -> transfer offered by the current owner, awaiting the recipient
+pub const PENDING_TRANSFERS: Map<U64Key, PendingTransfer> = Map::new("pending_transfers");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Sale {
+    pub house_index: u64,
+    pub seller: Addr,
+    /// set by the seller to reserve the sale, otherwise by whoever deposits first
+    pub buyer: Option<Addr>,
+    pub price: Asset,
+    /// whether the buyer's payment is held in escrow by the contract
+    pub funded: bool,
+}
+
+// house_index -> sale in progress
+pub const SALES: Map<U64Key, Sale> = Map::new("sales");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Lien {
+    pub lien_id: u64,
+    pub house_index: u64,
+    pub lienholder: Addr,
+    pub amount: Coin,
+    pub recorded_at: Timestamp,
+    /// 1 is the most senior of the unreleased liens on the property
+    pub priority: u32,
+    pub released_at: Option<Timestamp>,
+    /// the lienholder allows the next title transfer to go through
+    pub transfer_consent: bool,
+}
+
+impl Lien {
+    pub fn is_active(&self) -> bool {
+        self.released_at.is_none()
+    }
+}
+
+// (house_index, lien_id) -> lien
+pub const LIENS: Map<(U64Key, U64Key), Lien> = Map::new("liens");
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EncumbranceKind {
+    RightOfWay,
+    UtilityEasement,
+    ConservationEasement,
+    RestrictiveCovenant,
+    Other,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Beneficiary {
+    /// appurtenant to another parcel, so it follows that parcel's owner
+    Parcel { house_index: u64 },
+    /// held by a person or utility
+    Address { address: Addr },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Encumbrance {
+    pub encumbrance_id: u64,
+    pub house_index: u64,
+    pub kind: EncumbranceKind,
+    pub beneficiary: Beneficiary,
+    pub terms: String,
+    /// sha256 of the recorded instrument, hex encoded
+    pub document_hash: String,
+    pub recorded_at: Timestamp,
+    pub released_at: Option<Timestamp>,
+}
+
+// (house_index, encumbrance_id) -> encumbrance
+pub const ENCUMBRANCES: Map<(U64Key, U64Key), Encumbrance> = Map::new("encumbrances");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeOutcome {
+    /// the current owner keeps the title
+    OwnerConfirmed,
+    /// the title is awarded to another party
+    TitleAwarded { new_owner: Addr },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Dispute {
+    pub dispute_id: u64,
+    pub house_index: u64,
+    pub reason: String,
+    pub case_ref: String,
+    pub frozen_by: Addr,
+    pub frozen_at: Timestamp,
+    /// status of the property before the freeze
+    pub prior_status: PropertyStatus,
+    pub outcome: Option<DisputeOutcome>,
+    pub resolved_by: Option<Addr>,
+    pub resolved_at: Option<Timestamp>,
+}
+
+// (house_index, dispute_id) -> dispute
+pub const DISPUTES: Map<(U64Key, U64Key), Dispute> = Map::new("disputes");
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RegistrationStatus {
+    Open,
+    Approved,
+    Rejected,
+    Expired,
+}
+
+impl RegistrationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RegistrationStatus::Open => "open",
+            RegistrationStatus::Approved => "approved",
+            RegistrationStatus::Rejected => "rejected",
+            RegistrationStatus::Expired => "expired",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RegistrationRequest {
+    pub house_index: u64,
+    /// every pending record brought into force by the request, starting with
+    /// `house_index`
+    pub records: Vec<u64>,
+    /// titles retired once the request is approved, for a subdivision or merger
+    pub retires: Vec<u64>,
+    /// recorded in the chain of title of each new record
+    pub memo: Option<String>,
+    pub proposer: Addr,
+    pub approvals: Vec<Addr>,
+    pub rejections: Vec<Addr>,
+    pub submitted_at: Timestamp,
+    pub expires: Timestamp,
+    pub status: RegistrationStatus,
+}
+
+impl RegistrationRequest {
+    pub fn has_voted(&self, registrar: &Addr) -> bool {
+        self.approvals.contains(registrar) || self.rejections.contains(registrar)
+    }
+}
+
+// house_index -> request to bring pending properties into force
+pub const REGISTRATION_REQUESTS: Map<U64Key, RegistrationRequest> =
+    Map::new("registration_requests");
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentType {
+    Deed,
+    Survey,
+    Mortgage,
+    CourtOrder,
+    Other,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Document {
+    pub house_index: u64,
+    /// sha256 of the document, lower case hex
+    pub sha256: String,
+    /// where the document can be fetched, e.g. an IPFS CID
+    pub uri: String,
+    pub document_type: DocumentType,
+    pub uploader: Addr,
+    pub anchored_at: Timestamp,
+    /// the title transfer this document evidences, if any
+    pub transfer_sequence: Option<u64>,
+}
+
+// (house_index, sha256) -> document
+pub const DOCUMENTS: Map<(U64Key, String), Document> = Map::new("documents");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NftApproval {
+    pub spender: Addr,
+    pub expires: Expiration,
+}
+
+// house_index -> addresses allowed to transfer that title token
+pub const NFT_APPROVALS: Map<U64Key, Vec<NftApproval>> = Map::new("nft_approvals");
+// (owner, operator) -> expiration, operators may transfer all of an owner's tokens
+pub const NFT_OPERATORS: Map<(&Addr, &Addr), Expiration> = Map::new("nft_operators");
+
+const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TaxRate {
+    pub land_use: LandUse,
+    /// yearly share of the assessed value
+    pub rate: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TaxPolicy {
+    /// assesses properties and receives the tax collected
+    pub authority: Addr,
+    pub denom: Denom,
+    /// land-use classes without a rate are not taxed
+    pub rates: Vec<TaxRate>,
+    /// seconds tax may stay unpaid before the property becomes delinquent
+    pub grace_period: u64,
+}
+
+impl TaxPolicy {
+    pub fn rate_for(&self, land_use: LandUse) -> Decimal {
+        self.rates
+            .iter()
+            .find(|rate| rate.land_use == land_use)
+            .map_or_else(Decimal::zero, |rate| rate.rate)
+    }
+}
+
+pub const TAX_POLICY: Item<TaxPolicy> = Item::new("tax_policy");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TaxAccount {
+    pub house_index: u64,
+    pub assessed_value: Uint128,
+    pub assessed_at: Timestamp,
+    /// tax accrued up to `accrued_until` and not paid yet
+    pub arrears: Uint128,
+    pub accrued_until: Timestamp,
+    pub last_payment: Option<Timestamp>,
+    /// yearly rate fixed at the last assessment, policy changes only reach
+    /// the account when it is assessed again
+    pub rate: Decimal,
+}
+
+impl TaxAccount {
+    /// Tax owed at `now`, accruing linearly on the assessed value.
+    pub fn outstanding(&self, now: Timestamp) -> Uint128 {
+        let elapsed = now.seconds().saturating_sub(self.accrued_until.seconds());
+        let accrued = (self.assessed_value * self.rate).multiply_ratio(elapsed, SECONDS_PER_YEAR);
+        self.arrears + accrued
+    }
+
+    /// Tax still owed that accrued more than `grace_period` seconds ago.
+    pub fn overdue(&self, now: Timestamp, grace_period: u64) -> Uint128 {
+        let recent =
+            (self.assessed_value * self.rate).multiply_ratio(grace_period, SECONDS_PER_YEAR);
+        self.outstanding(now).saturating_sub(recent)
+    }
+
+    /// Moves everything accrued so far into the arrears.
+    pub fn accrue(&mut self, now: Timestamp) {
+        self.arrears = self.outstanding(now);
+        self.accrued_until = now;
+    }
+}
+
+// house_index -> tax assessment and balance
+pub const TAX_ACCOUNTS: Map<U64Key, TaxAccount> = Map::new("tax_accounts");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TaxSaleBid {
+    pub bidder: Addr,
+    pub amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TaxSale {
+    pub house_index: u64,
+    /// the tax owed when the auction started
+    pub minimum_bid: Asset,
+    pub started_at: Timestamp,
+    pub ends_at: Timestamp,
+    /// held in escrow until outbid or the auction is closed
+    pub highest_bid: Option<TaxSaleBid>,
+}
+
+// house_index -> auction of a delinquent property
+pub const TAX_SALES: Map<U64Key, TaxSale> = Map::new("tax_sales");
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaseStatus {
+    /// waiting for the tenant to accept
+    Proposed,
+    Active,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Lease {
+    pub lease_id: u64,
+    pub house_index: u64,
+    /// owner of the property when the lease was created, rent goes to the current owner
+    pub landlord: Addr,
+    pub tenant: Addr,
+    /// due every `period` seconds
+    pub rent: Asset,
+    pub period: u64,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    /// in the denom of the rent
+    pub deposit: Uint128,
+    pub status: LeaseStatus,
+    pub created_at: Timestamp,
+    pub accepted_at: Option<Timestamp>,
+    /// rent has been paid for the time up to here
+    pub paid_through: Timestamp,
+    /// deductions the landlord claims from the deposit at the end of the lease
+    pub claims: Vec<DepositClaim>,
+    pub deposit_released_at: Option<Timestamp>,
+}
+
+impl Lease {
+    pub fn is_active(&self, now: Timestamp) -> bool {
+        self.status == LeaseStatus::Active && now < self.end
+    }
+
+    /// The deposit is escrowed from acceptance until it is released.
+    pub fn holds_deposit(&self) -> bool {
+        self.status == LeaseStatus::Active
+            && !self.deposit.is_zero()
+            && self.deposit_released_at.is_none()
+    }
+
+    /// Sum of the claims that have not been rejected.
+    pub fn claimed(&self) -> Uint128 {
+        self.claims
+            .iter()
+            .filter(|claim| claim.status != ClaimStatus::Rejected)
+            .map(|claim| claim.amount)
+            .sum()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaimStatus {
+    /// paid to the landlord unless disputed in time
+    Pending,
+    /// waiting for an arbitrator
+    Disputed,
+    Upheld,
+    Rejected,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DepositClaim {
+    pub claim_id: u64,
+    pub amount: Uint128,
+    pub reason: String,
+    pub filed_at: Timestamp,
+    pub status: ClaimStatus,
+}
+
+pub struct LeaseIndexes<'a> {
+    // (tenant, pk) -> pk, to list the leases of a tenant
+    pub tenant: MultiIndex<'a, (Vec<u8>, Vec<u8>), Lease>,
+}
+
+impl<'a> IndexList<Lease> for LeaseIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Lease>> + '_> {
+        let v: Vec<&dyn Index<Lease>> = vec![&self.tenant];
+        Box::new(v.into_iter())
+    }
+}
+
+// (house_index, lease_id) -> lease
+pub fn leases<'a>() -> IndexedMap<'a, (U64Key, U64Key), Lease, LeaseIndexes<'a>> {
+    let indexes = LeaseIndexes {
+        tenant: MultiIndex::new(
+            |d: &Lease, k: Vec<u8>| (d.tenant.as_bytes().to_vec(), k),
+            "leases",
+            "leases__tenant",
+        ),
+    };
+    IndexedMap::new("leases", indexes)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Listing {
+    pub house_index: u64,
+    pub seller: Addr,
+    /// asking price, anyone paying it buys the property
+    pub price: Asset,
+    pub listed_at: Timestamp,
+    pub expires: Option<Timestamp>,
+}
+
+impl Listing {
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        self.expires.map_or(false, |expires| expires <= now)
+    }
+}
+
+// house_index -> marketplace listing
+pub const LISTINGS: Map<U64Key, Listing> = Map::new("listings");
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OfferStatus {
+    Open,
+    Accepted,
+    Withdrawn,
+    /// refunded because the title changed hands
+    Lost,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Offer {
+    pub offer_id: u64,
+    pub house_index: u64,
+    pub buyer: Addr,
+    /// held in escrow while the offer is open
+    pub amount: Asset,
+    /// price asked back by the owner, in the denom of the offer
+    pub counter: Option<Uint128>,
+    pub made_at: Timestamp,
+    pub status: OfferStatus,
+}
+
+// (house_index, offer_id) -> offer
+pub const OFFERS: Map<(U64Key, U64Key), Offer> = Map::new("offers");
+// house_index -> smallest offer the owner will consider
+pub const MINIMUM_OFFERS: Map<U64Key, Asset> = Map::new("minimum_offers");